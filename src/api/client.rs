@@ -5,6 +5,7 @@ use serde_json::{Value, json};
 
 use super::types::GraphQLResponse;
 
+#[derive(Clone)]
 pub struct LinearClient {
     client: Client,
     api_key: String,
@@ -59,3 +60,19 @@ impl LinearClient {
         gql.data.context("No data in GraphQL response")
     }
 }
+
+/// Whether `err` came from the connection rather than from Linear's answer, so a
+/// mutation may or may not have been applied (connection reset, timeout, ...).
+pub fn is_transport_error(err: &anyhow::Error) -> bool {
+    err.chain()
+        .filter_map(|cause| cause.downcast_ref::<reqwest::Error>())
+        .any(|err| err.is_timeout() || err.is_connect() || err.is_request() || err.is_body())
+}
+
+/// Whether Linear rejected a create because an entity with that ID exists.
+pub fn is_conflict_error(err: &anyhow::Error) -> bool {
+    let message = err.to_string().to_lowercase();
+    ["already exists", "duplicate", "conflict"]
+        .iter()
+        .any(|needle| message.contains(needle))
+}
@@ -1,5 +1,61 @@
+/// Issue fields selected by `issue view` and by every mutation that returns an
+/// issue, so `--json` output has the same shape everywhere (`issue view` also
+/// adds `comments`).
+macro_rules! issue_fields {
+    () => {
+        "
+        id
+        identifier
+        title
+        description
+        priority
+        estimate
+        url
+        branchName
+        createdAt
+        updatedAt
+        archivedAt
+        trashed
+        dueDate
+        state { id name type color }
+        team { id key name }
+        assignee { id name email displayName }
+        creator { id name email displayName }
+        labels { nodes { id name color } }
+        project { id name }
+        projectMilestone { id name }
+        cycle { id number name startsAt endsAt }
+        parent { id identifier title url state { id name type color } }
+        children(first: 250) {
+            nodes { id identifier title url state { id name type color } }
+        }
+        relations(first: 100) {
+            nodes {
+                id
+                type
+                relatedIssue { id identifier title url state { id name type color } }
+            }
+        }
+        inverseRelations(first: 100) {
+            nodes {
+                id
+                type
+                issue { id identifier title url state { id name type color } }
+            }
+        }
+        attachments(first: 100) {
+            nodes { id title subtitle url }
+        }
+        subscribers(first: 100) {
+            nodes { id name email displayName }
+        }
+"
+    };
+}
+
 pub mod client;
 pub mod mutations;
+pub mod paginate;
 pub mod queries;
 pub mod resolve;
 pub mod types;
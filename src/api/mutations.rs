@@ -1,31 +1,105 @@
-pub const ISSUE_CREATE: &str = r#"
+pub const ISSUE_CREATE: &str = concat!(
+    "
 mutation IssueCreate($input: IssueCreateInput!) {
     issueCreate(input: $input) {
         success
-        issue {
-            id
-            identifier
-            title
-            url
-        }
+        issue {",
+    issue_fields!(),
+    "        }
     }
 }
-"#;
+"
+);
 
-pub const ISSUE_UPDATE: &str = r#"
+pub const ISSUE_UPDATE: &str = concat!(
+    "
 mutation IssueUpdate($id: String!, $input: IssueUpdateInput!) {
     issueUpdate(id: $id, input: $input) {
         success
-        issue {
+        issue {",
+    issue_fields!(),
+    "        }
+    }
+}
+"
+);
+
+pub const ISSUE_SUBSCRIBE: &str = concat!(
+    "
+mutation IssueSubscribe($id: String!, $userId: String) {
+    issueSubscribe(id: $id, userId: $userId) {
+        success
+        issue {",
+    issue_fields!(),
+    "        }
+    }
+}
+"
+);
+
+pub const ISSUE_UNSUBSCRIBE: &str = concat!(
+    "
+mutation IssueUnsubscribe($id: String!, $userId: String) {
+    issueUnsubscribe(id: $id, userId: $userId) {
+        success
+        issue {",
+    issue_fields!(),
+    "        }
+    }
+}
+"
+);
+
+pub const ISSUE_ARCHIVE: &str = r#"
+mutation IssueArchive($id: String!) {
+    issueArchive(id: $id) {
+        success
+        entity { id identifier title url archivedAt trashed }
+    }
+}
+"#;
+
+pub const ISSUE_UNARCHIVE: &str = r#"
+mutation IssueUnarchive($id: String!) {
+    issueUnarchive(id: $id) {
+        success
+        entity { id identifier title url archivedAt trashed }
+    }
+}
+"#;
+
+/// Moves the issue to the trash unless `permanentlyDelete` is set.
+pub const ISSUE_DELETE: &str = r#"
+mutation IssueDelete($id: String!, $permanentlyDelete: Boolean) {
+    issueDelete(id: $id, permanentlyDelete: $permanentlyDelete) {
+        success
+        entity { id identifier title url archivedAt trashed }
+    }
+}
+"#;
+
+pub const ISSUE_RELATION_CREATE: &str = r#"
+mutation IssueRelationCreate($input: IssueRelationCreateInput!) {
+    issueRelationCreate(input: $input) {
+        success
+        issueRelation {
             id
-            identifier
-            title
-            url
+            type
+            issue { id identifier title url state { id name type color } }
+            relatedIssue { id identifier title url state { id name type color } }
         }
     }
 }
 "#;
 
+pub const ISSUE_RELATION_DELETE: &str = r#"
+mutation IssueRelationDelete($id: String!) {
+    issueRelationDelete(id: $id) {
+        success
+    }
+}
+"#;
+
 pub const COMMENT_CREATE: &str = r#"
 mutation CommentCreate($input: CommentCreateInput!) {
     commentCreate(input: $input) {
@@ -35,6 +109,62 @@ mutation CommentCreate($input: CommentCreateInput!) {
             body
             createdAt
             user { id name displayName }
+            parent { id }
+        }
+    }
+}
+"#;
+
+pub const COMMENT_UPDATE: &str = r#"
+mutation CommentUpdate($id: String!, $input: CommentUpdateInput!) {
+    commentUpdate(id: $id, input: $input) {
+        success
+        comment {
+            id
+            body
+            createdAt
+            editedAt
+            user { id name displayName }
+            parent { id }
+        }
+    }
+}
+"#;
+
+pub const COMMENT_DELETE: &str = r#"
+mutation CommentDelete($id: String!) {
+    commentDelete(id: $id) {
+        success
+    }
+}
+"#;
+
+pub const COMMENT_RESOLVE: &str = r#"
+mutation CommentResolve($id: String!) {
+    commentResolve(id: $id) {
+        success
+        comment {
+            id
+            body
+            createdAt
+            resolvedAt
+            user { id name displayName }
+            resolvingUser { id name displayName }
+        }
+    }
+}
+"#;
+
+pub const COMMENT_UNRESOLVE: &str = r#"
+mutation CommentUnresolve($id: String!) {
+    commentUnresolve(id: $id) {
+        success
+        comment {
+            id
+            body
+            createdAt
+            resolvedAt
+            user { id name displayName }
         }
     }
 }
@@ -0,0 +1,122 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use super::client::LinearClient;
+use super::queries;
+use super::types::*;
+
+const PAGE_SIZE: usize = 100;
+
+/// Follow `pageInfo.endCursor` until the connection is exhausted or `limit`
+/// nodes have been collected. The query must accept `$first` and `$after`.
+pub async fn collect<R, T, F>(
+    client: &LinearClient,
+    query: &str,
+    mut variables: Value,
+    limit: Option<usize>,
+    connection: F,
+) -> Result<Vec<T>>
+where
+    R: DeserializeOwned,
+    T: Serialize,
+    F: Fn(R) -> Connection<T>,
+{
+    let mut nodes = Vec::new();
+    loop {
+        let remaining = limit.map_or(PAGE_SIZE, |l| l.saturating_sub(nodes.len()));
+        if remaining == 0 {
+            break;
+        }
+        variables["first"] = json!(remaining.min(PAGE_SIZE));
+
+        let resp: R = client.query(query, variables.clone()).await?;
+        let page = connection(resp);
+        nodes.extend(page.nodes);
+
+        match page.page_info {
+            Some(PageInfo {
+                has_next_page: true,
+                end_cursor: Some(cursor),
+            }) => variables["after"] = json!(cursor),
+            _ => break,
+        }
+    }
+    Ok(nodes)
+}
+
+/// All comments on an issue, including replies, oldest first.
+pub async fn issue_comments(
+    client: &LinearClient,
+    issue_id: &str,
+    limit: Option<usize>,
+) -> Result<Vec<Comment>> {
+    let fetched = collect(
+        client,
+        queries::ISSUE_COMMENTS,
+        json!({ "id": issue_id }),
+        limit,
+        |resp: IssueCommentsResponse| resp.issue.comments,
+    )
+    .await?;
+
+    let mut comments: Vec<Comment> = Vec::new();
+    for mut comment in fetched {
+        let has_replies = comment.children.take().is_some_and(|c| !c.nodes.is_empty());
+        let replies = if has_replies && comment.parent.is_none() {
+            comment_replies(client, &comment.id).await?
+        } else {
+            Vec::new()
+        };
+        for reply in std::iter::once(comment).chain(replies) {
+            if !comments.iter().any(|c| c.id == reply.id) {
+                comments.push(reply);
+            }
+        }
+    }
+    comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    if let Some(limit) = limit {
+        comments.truncate(limit);
+    }
+    Ok(comments)
+}
+
+/// Every reply to a comment.
+async fn comment_replies(client: &LinearClient, comment_id: &str) -> Result<Vec<Comment>> {
+    collect(
+        client,
+        queries::COMMENT_REPLIES,
+        json!({ "id": comment_id }),
+        None,
+        |resp: CommentRepliesResponse| resp.comment.children,
+    )
+    .await
+}
+
+/// Every direct sub-issue of an issue. The `children` field of each node only
+/// says whether it has sub-issues of its own.
+pub async fn issue_children(client: &LinearClient, issue_id: &str) -> Result<Vec<Issue>> {
+    collect(
+        client,
+        queries::ISSUE_CHILDREN,
+        json!({ "id": issue_id }),
+        None,
+        |resp: IssueChildrenResponse| resp.issue.children,
+    )
+    .await
+}
+
+/// An issue's full change log, oldest first.
+pub async fn issue_history(client: &LinearClient, issue_id: &str) -> Result<Vec<IssueHistory>> {
+    let mut entries = collect(
+        client,
+        queries::ISSUE_HISTORY,
+        json!({ "id": issue_id }),
+        None,
+        |resp: IssueHistoryResponse| resp.issue.history,
+    )
+    .await?;
+    entries.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    Ok(entries)
+}
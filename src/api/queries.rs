@@ -22,8 +22,14 @@ query {
 "#;
 
 pub const ISSUES: &str = r#"
-query Issues($filter: IssueFilter, $first: Int, $after: String) {
-    issues(filter: $filter, first: $first, after: $after, orderBy: updatedAt) {
+query Issues($filter: IssueFilter, $first: Int, $after: String, $includeArchived: Boolean) {
+    issues(
+        filter: $filter
+        first: $first
+        after: $after
+        orderBy: updatedAt
+        includeArchived: $includeArchived
+    ) {
         nodes {
             id
             identifier
@@ -32,11 +38,16 @@ query Issues($filter: IssueFilter, $first: Int, $after: String) {
             url
             createdAt
             updatedAt
+            archivedAt
+            trashed
+            estimate
+            dueDate
             state { id name type color }
             team { id key name }
             assignee { id name displayName }
             labels { nodes { id name color } }
             project { id name }
+            cycle { id number name }
         }
         pageInfo {
             hasNextPage
@@ -46,35 +57,164 @@ query Issues($filter: IssueFilter, $first: Int, $after: String) {
 }
 "#;
 
-pub const ISSUE: &str = r#"
+pub const ISSUE: &str = concat!(
+    "
 query Issue($id: String!) {
+    issue(id: $id) {",
+    issue_fields!(),
+    "    }
+}
+"
+);
+
+pub const ISSUE_REF: &str = r#"
+query IssueRef($id: String!) {
     issue(id: $id) {
         id
         identifier
         title
-        description
-        priority
-        estimate
         url
-        createdAt
-        updatedAt
+        branchName
         state { id name type color }
         team { id key name }
-        assignee { id name email displayName }
-        labels { nodes { id name color } }
-        project { id name }
-        comments(first: 100) {
+    }
+}
+"#;
+
+/// Direct sub-issues. Each node carries a one-element `children` probe so
+/// callers can tell leaves apart without another request.
+pub const ISSUE_CHILDREN: &str = r#"
+query IssueChildren($id: String!, $first: Int, $after: String) {
+    issue(id: $id) {
+        children(first: $first, after: $after) {
+            nodes {
+                id
+                identifier
+                title
+                priority
+                url
+                createdAt
+                updatedAt
+                state { id name type color }
+                team { id key name }
+                assignee { id name displayName }
+                labels { nodes { id name color } }
+                project { id name }
+                children(first: 1) { nodes { id } }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+    }
+}
+"#;
+
+pub const ISSUE_HISTORY: &str = r#"
+query IssueHistory($id: String!, $first: Int, $after: String) {
+    issue(id: $id) {
+        history(first: $first, after: $after) {
+            nodes {
+                id
+                createdAt
+                actor { id name email displayName }
+                fromState { id name type color }
+                toState { id name type color }
+                fromAssignee { id name email displayName }
+                toAssignee { id name email displayName }
+                addedLabels { id name color }
+                removedLabels { id name color }
+                fromPriority
+                toPriority
+                fromEstimate
+                toEstimate
+                fromTitle
+                toTitle
+                fromDueDate
+                toDueDate
+                fromProject { id name }
+                toProject { id name }
+                fromCycle { id number name }
+                toCycle { id number name }
+                fromParent { id identifier title url }
+                toParent { id identifier title url }
+                updatedDescription
+                archived
+                trashed
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+    }
+}
+"#;
+
+pub const ISSUE_COMMENTS: &str = r#"
+query IssueComments($id: String!, $first: Int, $after: String) {
+    issue(id: $id) {
+        comments(first: $first, after: $after) {
             nodes {
                 id
                 body
                 createdAt
+                editedAt
+                resolvedAt
                 user { id name displayName }
+                resolvingUser { id name displayName }
+                parent { id }
+                children(first: 1) { nodes { id } }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
             }
         }
     }
 }
 "#;
 
+/// Replies to a comment, paged separately so long threads are not cut off.
+pub const COMMENT_REPLIES: &str = r#"
+query CommentReplies($id: String!, $first: Int, $after: String) {
+    comment(id: $id) {
+        children(first: $first, after: $after) {
+            nodes {
+                id
+                body
+                createdAt
+                editedAt
+                resolvedAt
+                user { id name displayName }
+                resolvingUser { id name displayName }
+                parent { id }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+    }
+}
+"#;
+
+pub const COMMENT: &str = r#"
+query Comment($id: String!) {
+    comment(id: $id) {
+        id
+        body
+        createdAt
+        editedAt
+        resolvedAt
+        user { id name displayName }
+        parent { id }
+        issue { id identifier title url }
+    }
+}
+"#;
+
 pub const ISSUE_SEARCH: &str = r#"
 query SearchIssues($term: String!, $first: Int) {
     searchIssues(term: $term, first: $first) {
@@ -101,6 +241,7 @@ query WorkflowStates($filter: WorkflowStateFilter) {
             name
             type
             color
+            position
         }
     }
 }
@@ -121,8 +262,8 @@ query Users($first: Int) {
 "#;
 
 pub const LABELS: &str = r#"
-query Labels($first: Int) {
-    issueLabels(first: $first) {
+query Labels($filter: IssueLabelFilter, $first: Int) {
+    issueLabels(filter: $filter, first: $first) {
         nodes {
             id
             name
@@ -131,3 +272,44 @@ query Labels($first: Int) {
     }
 }
 "#;
+
+pub const PROJECTS: &str = r#"
+query Projects($first: Int) {
+    projects(first: $first) {
+        nodes {
+            id
+            name
+        }
+    }
+}
+"#;
+
+pub const CYCLES: &str = r#"
+query Cycles($filter: CycleFilter, $first: Int) {
+    cycles(filter: $filter, first: $first) {
+        nodes {
+            id
+            number
+            name
+            startsAt
+            endsAt
+            isActive
+            isNext
+            isPrevious
+        }
+    }
+}
+"#;
+
+pub const PROJECT_MILESTONES: &str = r#"
+query ProjectMilestones($id: String!) {
+    project(id: $id) {
+        projectMilestones(first: 250) {
+            nodes {
+                id
+                name
+            }
+        }
+    }
+}
+"#;
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use anyhow::{Result, bail};
 use serde_json::json;
 
@@ -22,6 +24,14 @@ pub async fn team_id(client: &LinearClient, key: &str) -> Result<String> {
         .ok_or_else(|| anyhow::anyhow!("Team '{key}' not found"))
 }
 
+/// Internal ID of an issue given its identifier (e.g. ENG-123) or ID.
+pub async fn issue_id(client: &LinearClient, id: &str) -> Result<String> {
+    let resp: IssueResponse = client
+        .query(queries::ISSUE_REF, json!({ "id": id.trim() }))
+        .await?;
+    Ok(resp.issue.id)
+}
+
 pub async fn user_id(client: &LinearClient, name: &str) -> Result<String> {
     if name.eq_ignore_ascii_case("me") {
         let resp: ViewerResponse = client.query(queries::VIEWER, json!({})).await?;
@@ -92,6 +102,32 @@ pub async fn state_id(client: &LinearClient, team_id: &str, name: &str) -> Resul
         .ok_or_else(|| anyhow::anyhow!("State '{name}' not found for this team"))
 }
 
+/// Workflow states of one type (`started`, `completed`, ...) in a team, in board order.
+pub async fn states_of_type(
+    client: &LinearClient,
+    team_id: &str,
+    state_type: &str,
+) -> Result<Vec<WorkflowState>> {
+    let resp: WorkflowStatesResponse = client
+        .query(
+            queries::WORKFLOW_STATES,
+            json!({
+                "filter": {
+                    "team": { "id": { "eq": team_id } },
+                    "type": { "eq": state_type },
+                }
+            }),
+        )
+        .await?;
+    let mut states = resp.workflow_states.nodes;
+    states.sort_by(|a, b| {
+        a.position
+            .partial_cmp(&b.position)
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(states)
+}
+
 pub async fn label_id(client: &LinearClient, name: &str) -> Result<String> {
     let name = name.trim();
     if name.is_empty() {
@@ -132,6 +168,97 @@ pub async fn label_id(client: &LinearClient, name: &str) -> Result<String> {
     }
 }
 
+pub async fn project_id(client: &LinearClient, name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Project cannot be empty");
+    }
+
+    let resp: ProjectsResponse = client
+        .query(queries::PROJECTS, json!({ "first": LOOKUP_LIMIT }))
+        .await?;
+    let projects = resp.projects.nodes;
+
+    let exact: Vec<&Project> = projects
+        .iter()
+        .filter(|p| equals_ignore_case(p.name.as_deref(), name))
+        .collect();
+    match exact.len() {
+        1 => return Ok(exact[0].id.clone()),
+        2.. => bail!(
+            "Ambiguous project '{name}'. Matches: {}.",
+            render_project_candidates(&exact)
+        ),
+        _ => {}
+    }
+
+    let lower = name.to_lowercase();
+    let fuzzy: Vec<&Project> = projects
+        .iter()
+        .filter(|p| contains_ignore_case(p.name.as_deref(), &lower))
+        .collect();
+    match fuzzy.len() {
+        1 => Ok(fuzzy[0].id.clone()),
+        2.. => bail!(
+            "Ambiguous project '{name}'. Matches: {}.",
+            render_project_candidates(&fuzzy)
+        ),
+        _ => bail!("Project '{name}' not found"),
+    }
+}
+
+/// A cycle of the team by number, name, or `current`/`next`/`previous`.
+pub async fn cycle_id(client: &LinearClient, team_id: &str, value: &str) -> Result<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        bail!("Cycle cannot be empty");
+    }
+
+    let resp: CyclesResponse = client
+        .query(
+            queries::CYCLES,
+            json!({
+                "filter": { "team": { "id": { "eq": team_id } } },
+                "first": LOOKUP_LIMIT,
+            }),
+        )
+        .await?;
+    let cycles = resp.cycles.nodes;
+
+    let found = match value.to_lowercase().as_str() {
+        "current" | "active" => cycles.iter().find(|c| c.is_active == Some(true)),
+        "next" => cycles.iter().find(|c| c.is_next == Some(true)),
+        "previous" | "last" => cycles.iter().find(|c| c.is_previous == Some(true)),
+        _ => match value.parse::<f64>() {
+            Ok(number) => cycles.iter().find(|c| c.number == Some(number)),
+            Err(_) => cycles
+                .iter()
+                .find(|c| equals_ignore_case(c.name.as_deref(), value)),
+        },
+    };
+    found
+        .map(|c| c.id.clone())
+        .ok_or_else(|| anyhow::anyhow!("Cycle '{value}' not found for this team"))
+}
+
+pub async fn milestone_id(client: &LinearClient, project_id: &str, name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        bail!("Milestone cannot be empty");
+    }
+
+    let resp: ProjectMilestonesResponse = client
+        .query(queries::PROJECT_MILESTONES, json!({ "id": project_id }))
+        .await?;
+    resp.project
+        .project_milestones
+        .nodes
+        .iter()
+        .find(|m| equals_ignore_case(m.name.as_deref(), name))
+        .map(|m| m.id.clone())
+        .ok_or_else(|| anyhow::anyhow!("Milestone '{name}' not found in this project"))
+}
+
 fn equals_ignore_case(value: Option<&str>, query: &str) -> bool {
     value.is_some_and(|v| v.eq_ignore_ascii_case(query))
 }
@@ -182,3 +309,18 @@ fn render_label_candidates(labels: &[&Label]) -> String {
         formatted.join(", ")
     }
 }
+
+fn render_project_candidates(projects: &[&Project]) -> String {
+    let mut formatted: Vec<String> = projects
+        .iter()
+        .map(|p| p.name.as_deref().unwrap_or("Unnamed").to_string())
+        .collect();
+
+    if formatted.len() > CANDIDATE_PREVIEW_LIMIT {
+        let extra = formatted.len() - CANDIDATE_PREVIEW_LIMIT;
+        formatted.truncate(CANDIDATE_PREVIEW_LIMIT);
+        format!("{} (+{} more)", formatted.join(", "), extra)
+    } else {
+        formatted.join(", ")
+    }
+}
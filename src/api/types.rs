@@ -36,8 +36,6 @@ pub struct User {
     pub email: Option<String>,
     #[serde(rename = "displayName")]
     pub display_name: Option<String>,
-    #[serde(skip_serializing)]
-    pub active: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -53,8 +51,8 @@ pub struct WorkflowState {
     pub name: Option<String>,
     #[serde(rename = "type")]
     pub state_type: Option<String>,
-    #[serde(skip_serializing)]
-    pub color: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub position: Option<f64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -71,6 +69,162 @@ pub struct Project {
     pub name: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Cycle {
+    pub id: String,
+    pub number: Option<f64>,
+    pub name: Option<String>,
+    #[serde(rename = "startsAt")]
+    pub starts_at: Option<String>,
+    #[serde(rename = "endsAt")]
+    pub ends_at: Option<String>,
+    #[serde(rename = "isActive", default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    #[serde(rename = "isNext", default, skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<bool>,
+    #[serde(
+        rename = "isPrevious",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub is_previous: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Attachment {
+    pub id: String,
+    pub title: Option<String>,
+    pub subtitle: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A relation as seen from one side: `relatedIssue` is set on outbound
+/// relations (`Issue.relations`), `issue` on inbound ones (`Issue.inverseRelations`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueRelation {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub relation_type: Option<String>,
+    #[serde(
+        rename = "relatedIssue",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub related_issue: Option<Box<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+}
+
+/// One entry of an issue's change log. Only the fields touched by the change
+/// are set; `from*` is the old value and `to*` the new one.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueHistory {
+    pub id: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    pub actor: Option<User>,
+    #[serde(rename = "fromState", default, skip_serializing_if = "Option::is_none")]
+    pub from_state: Option<WorkflowState>,
+    #[serde(rename = "toState", default, skip_serializing_if = "Option::is_none")]
+    pub to_state: Option<WorkflowState>,
+    #[serde(
+        rename = "fromAssignee",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_assignee: Option<User>,
+    #[serde(
+        rename = "toAssignee",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_assignee: Option<User>,
+    #[serde(
+        rename = "addedLabels",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub added_labels: Option<Vec<Label>>,
+    #[serde(
+        rename = "removedLabels",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub removed_labels: Option<Vec<Label>>,
+    #[serde(
+        rename = "fromPriority",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_priority: Option<f64>,
+    #[serde(
+        rename = "toPriority",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_priority: Option<f64>,
+    #[serde(
+        rename = "fromEstimate",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_estimate: Option<f64>,
+    #[serde(
+        rename = "toEstimate",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_estimate: Option<f64>,
+    #[serde(rename = "fromTitle", default, skip_serializing_if = "Option::is_none")]
+    pub from_title: Option<String>,
+    #[serde(rename = "toTitle", default, skip_serializing_if = "Option::is_none")]
+    pub to_title: Option<String>,
+    #[serde(
+        rename = "fromDueDate",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_due_date: Option<String>,
+    #[serde(rename = "toDueDate", default, skip_serializing_if = "Option::is_none")]
+    pub to_due_date: Option<String>,
+    #[serde(
+        rename = "fromProject",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_project: Option<Project>,
+    #[serde(rename = "toProject", default, skip_serializing_if = "Option::is_none")]
+    pub to_project: Option<Project>,
+    #[serde(rename = "fromCycle", default, skip_serializing_if = "Option::is_none")]
+    pub from_cycle: Option<Cycle>,
+    #[serde(rename = "toCycle", default, skip_serializing_if = "Option::is_none")]
+    pub to_cycle: Option<Cycle>,
+    #[serde(
+        rename = "fromParent",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_parent: Option<Box<Issue>>,
+    #[serde(rename = "toParent", default, skip_serializing_if = "Option::is_none")]
+    pub to_parent: Option<Box<Issue>>,
+    #[serde(
+        rename = "updatedDescription",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_description: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Comment {
     pub id: String,
@@ -78,6 +232,28 @@ pub struct Comment {
     #[serde(rename = "createdAt")]
     pub created_at: Option<String>,
     pub user: Option<User>,
+    #[serde(rename = "editedAt", default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<String>,
+    #[serde(
+        rename = "resolvedAt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub resolved_at: Option<String>,
+    #[serde(
+        rename = "resolvingUser",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub resolving_user: Option<User>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Comment>>,
+    /// A one-reply probe; replies are fetched and flattened into the issue's
+    /// comment list separately.
+    #[serde(default, skip_serializing)]
+    pub children: Option<Connection<Comment>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -89,6 +265,12 @@ pub struct Issue {
     pub priority: Option<i32>,
     pub estimate: Option<f64>,
     pub url: Option<String>,
+    #[serde(
+        rename = "branchName",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub branch_name: Option<String>,
     #[serde(rename = "createdAt")]
     pub created_at: Option<String>,
     #[serde(rename = "updatedAt")]
@@ -98,7 +280,44 @@ pub struct Issue {
     pub assignee: Option<User>,
     pub labels: Option<Connection<Label>>,
     pub project: Option<Project>,
+    #[serde(
+        rename = "projectMilestone",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub project_milestone: Option<ProjectMilestone>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub comments: Option<Connection<Comment>>,
+    #[serde(rename = "dueDate", default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(
+        rename = "archivedAt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub archived_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Cycle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Connection<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Connection<IssueRelation>>,
+    #[serde(
+        rename = "inverseRelations",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub inverse_relations: Option<Connection<IssueRelation>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Connection<Attachment>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Connection<User>>,
 }
 
 // Query response wrappers
@@ -122,6 +341,51 @@ pub struct IssueResponse {
     pub issue: Issue,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct IssueCommentsResponse {
+    pub issue: IssueComments,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueComments {
+    pub comments: Connection<Comment>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentRepliesResponse {
+    pub comment: CommentReplies,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentReplies {
+    pub children: Connection<Comment>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueChildrenResponse {
+    pub issue: IssueChildren,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueChildren {
+    pub children: Connection<Issue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueHistoryResponse {
+    pub issue: IssueHistoryConnection,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueHistoryConnection {
+    pub history: Connection<IssueHistory>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentResponse {
+    pub comment: Comment,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct IssueSearchResponse {
     #[serde(rename = "searchIssues")]
@@ -145,7 +409,52 @@ pub struct LabelsResponse {
     pub issue_labels: Connection<Label>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ProjectsResponse {
+    pub projects: Connection<Project>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CyclesResponse {
+    pub cycles: Connection<Cycle>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectMilestonesResponse {
+    pub project: ProjectMilestones,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectMilestones {
+    #[serde(rename = "projectMilestones")]
+    pub project_milestones: Connection<ProjectMilestone>,
+}
+
 // Mutation responses
+#[derive(Debug, Deserialize)]
+pub struct ArchiveResult {
+    pub success: bool,
+    pub entity: Option<Issue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueArchiveResponse {
+    #[serde(rename = "issueArchive")]
+    pub issue_archive: ArchiveResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueUnarchiveResponse {
+    #[serde(rename = "issueUnarchive")]
+    pub issue_unarchive: ArchiveResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueDeleteResponse {
+    #[serde(rename = "issueDelete")]
+    pub issue_delete: ArchiveResult,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct MutationResult {
     pub success: bool,
@@ -164,6 +473,37 @@ pub struct IssueUpdateResponse {
     pub issue_update: MutationResult,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct IssueSubscribeResponse {
+    #[serde(rename = "issueSubscribe")]
+    pub issue_subscribe: MutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueUnsubscribeResponse {
+    #[serde(rename = "issueUnsubscribe")]
+    pub issue_unsubscribe: MutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RelationMutationResult {
+    pub success: bool,
+    #[serde(rename = "issueRelation")]
+    pub issue_relation: Option<IssueRelation>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueRelationCreateResponse {
+    #[serde(rename = "issueRelationCreate")]
+    pub issue_relation_create: RelationMutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueRelationDeleteResponse {
+    #[serde(rename = "issueRelationDelete")]
+    pub issue_relation_delete: DeleteResult,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CommentMutationResult {
     pub success: bool,
@@ -175,3 +515,32 @@ pub struct CommentCreateResponse {
     #[serde(rename = "commentCreate")]
     pub comment_create: CommentMutationResult,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CommentUpdateResponse {
+    #[serde(rename = "commentUpdate")]
+    pub comment_update: CommentMutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentResolveResponse {
+    #[serde(rename = "commentResolve")]
+    pub comment_resolve: CommentMutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentUnresolveResponse {
+    #[serde(rename = "commentUnresolve")]
+    pub comment_unresolve: CommentMutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteResult {
+    pub success: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentDeleteResponse {
+    #[serde(rename = "commentDelete")]
+    pub comment_delete: DeleteResult,
+}
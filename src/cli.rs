@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
 #[command(
@@ -14,10 +14,23 @@ use clap::{Parser, Subcommand};
   linear api -q '{ viewer { id } }'  Run raw GraphQL"
 )]
 pub struct Cli {
+    /// When to use colors (NO_COLOR and CLICOLOR_FORCE are respected in auto mode)
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+    /// Do not pipe long output through $LINEAR_PAGER/$PAGER
+    #[arg(long, global = true)]
+    pub no_pager: bool,
     #[command(subcommand)]
     pub command: Commands,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Authenticate with Linear
@@ -30,7 +43,12 @@ pub enum Commands {
     /// Manage issues
     Issue {
         #[command(subcommand)]
-        command: IssueCommands,
+        command: Box<IssueCommands>,
+    },
+    /// Reply to, edit, delete and resolve comments
+    Comment {
+        #[command(subcommand)]
+        command: CommentCommands,
     },
     /// Manage teams
     Team {
@@ -49,9 +67,33 @@ pub enum Commands {
   linear issue view DIS-510 --json         View as JSON (for agents)
   linear issue list --json                 List as JSON (for agents)
   linear issue create --team ENG           Create issue (interactive)
+  linear issue create --team ENG --title 'Bug' --json
   linear issue update DIS-510 --state 'In Progress'
+  linear issue update DIS-510 --cycle next --estimate 3 --no-parent
+  linear issue start ENG-1                 Assign, move to started and check out its branch
+  linear issue done ENG-1 ENG-2            Complete issues, whatever the state is called
+  linear issue current                     Issue named by the current git branch
+  linear issue comment -b 'Fixed'          Comment on the current branch's issue
+  linear issue update ENG-1 --state-type started
+  linear issue bulk-update --team ENG --state Todo --set-cycle next --dry-run
   linear issue comment DIS-510 -b 'Fixed'  Add a comment
-  echo 'details...' | linear issue comment DIS-510"
+  linear issue delete ENG-1 ENG-2 --yes    Move issues to the trash
+  linear issue move ENG-1 --team OPS       Move to another team, keeping state and labels
+  linear issue copy ENG-1 --team OPS --with-comments --with-sub-issues
+  linear issue subscribe ENG-1 --user bob  Follow an issue on someone's behalf
+  linear issue relate ENG-1 blocks ENG-2   Record a dependency
+  linear issue relate ENG-3 --duplicate-of ENG-1
+  linear issue history ENG-1               Who changed what, and when
+  linear issue tree ENG-1                  Sub-issue hierarchy with progress
+  linear issue reparent ENG-4 ENG-5 --parent ENG-1
+  linear issue list --archived --team ENG  Archived and trashed issues
+  linear issue list --format ids | linear issue state - Done
+  linear issue comment DIS-510             Write a comment in $EDITOR
+  linear issue update DIS-510 --edit-description
+  linear issue edit DIS-510                Edit fields and description in $EDITOR
+  linear issue create --from-file docs/tasks/ --team ENG --dry-run
+  echo 'details...' | linear issue comment DIS-510
+  linear issue list --template '{{identifier}}\\t{{title | truncate 60}}'"
 )]
 pub enum IssueCommands {
     /// List issues with filters
@@ -59,49 +101,375 @@ pub enum IssueCommands {
     /// View issue details (aliases: get, show)
     #[command(alias = "get", alias = "show")]
     View {
-        /// Issue ID or identifier (e.g. ENG-123)
-        id: String,
+        /// Issue IDs or identifiers (e.g. ENG-123; "-" reads them from stdin);
+        /// defaults to the issue named by the current git branch
+        ids: Vec<String>,
         /// Output as JSON (for scripts and agents)
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
         json: bool,
+        /// Print description and comments as raw Markdown
+        #[arg(long)]
+        raw: bool,
+        /// How many comments to show: a number, "all" or "none"
+        #[arg(long, value_name = "N|all|none", default_value = "all")]
+        comments: CommentLimit,
+        #[command(flatten)]
+        template: TemplateArgs,
     },
     /// Create a new issue
     Create(CreateArgs),
+    /// Edit an issue as Markdown with TOML front matter in $EDITOR
+    Edit {
+        /// Issue ID or identifier (e.g. ENG-123)
+        id: String,
+        /// Apply the changes without asking for confirmation
+        #[arg(short, long)]
+        yes: bool,
+        /// Output the updated issue as JSON
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
     /// Update an existing issue
     Update(UpdateArgs),
+    /// Apply the same change to many issues, selected by filters or IDs
+    BulkUpdate(BulkUpdateArgs),
+    /// Show the issue named by the current git branch
+    Current {
+        /// Output as JSON (for scripts and agents)
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
     /// Search issues
     Search {
         /// Search query
         query: String,
         /// Output as JSON (for scripts and agents)
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
         json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
     },
     /// Assign an issue to a user
     Assign {
-        /// Issue ID or identifier
-        id: String,
-        /// User to assign (display name/name/email, or omit to unassign)
-        user: Option<String>,
+        /// Issue IDs ("-" reads them from stdin) followed by the user to assign
+        /// (display name/name/email); a single ID without a user unassigns it.
+        /// Without IDs, the issue named by the current git branch is assigned
+        #[arg(value_name = "ID")]
+        args: Vec<String>,
+        /// Unassign the issues; every argument is an issue ID
+        #[arg(long)]
+        unassign: bool,
+        /// Output the updated issue as JSON
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
     },
     /// Change issue workflow state
     State {
+        /// Issue IDs ("-" reads them from stdin) followed by the state name
+        /// (e.g. "In Progress"); with a single ID the state can be picked interactively.
+        /// Without IDs, the issue named by the current git branch is changed
+        #[arg(value_name = "ID")]
+        args: Vec<String>,
+        /// Use the team's state of this type instead of a name; every argument is an issue ID
+        #[arg(long, value_enum, value_name = "TYPE")]
+        state_type: Option<StateType>,
+        /// Output the updated issue as JSON
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
+    /// Start work on issues: assign them to you, move them to started and check out the branch
+    Start(StartArgs),
+    /// Move issues to their team's completed state
+    Done(TransitionArgs),
+    /// Move issues to their team's canceled state
+    Cancel(TransitionArgs),
+    /// Move issues to their team's backlog state
+    Backlog(TransitionArgs),
+    /// Move issues to their team's triage state
+    Triage(TransitionArgs),
+    /// Archive issues
+    Archive {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Bring archived issues back
+    Unarchive(IssueTargets),
+    /// Move issues to the trash (restorable for 30 days)
+    #[command(alias = "rm")]
+    Delete {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+        /// Delete permanently instead of moving to the trash (admins only)
+        #[arg(long)]
+        permanent: bool,
+    },
+    /// Restore issues from the trash
+    Restore(IssueTargets),
+    /// Move issues to another team, mapping workflow states and labels
+    Move {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Destination team key (e.g. OPS)
+        #[arg(long)]
+        team: String,
+    },
+    /// Copy issues, optionally into another team
+    Copy {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Destination team key (defaults to each issue's own team)
+        #[arg(long)]
+        team: Option<String>,
+        /// Also copy comments (attributed to their original authors in the text)
+        #[arg(long)]
+        with_comments: bool,
+        /// Also copy sub-issues, recursively
+        #[arg(long)]
+        with_sub_issues: bool,
+    },
+    /// Subscribe yourself (or --user) to issue notifications
+    Subscribe {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Subscribe this user instead (display name/name/email)
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Stop receiving notifications for issues (yourself, or --user)
+    Unsubscribe {
+        #[command(flatten)]
+        target: IssueTargets,
+        /// Unsubscribe this user instead (display name/name/email)
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Link two issues, e.g. `relate ENG-1 blocks ENG-2`
+    Relate {
         /// Issue ID or identifier
         id: String,
-        /// State name (e.g. "In Progress")
-        state: Option<String>,
+        /// How the first issue relates to the other one
+        #[arg(value_enum, requires = "other", required_unless_present = "duplicate_of")]
+        relation: Option<RelationKind>,
+        /// The other issue
+        other: Option<String>,
+        /// Mark the issue as a duplicate of this one
+        #[arg(long, value_name = "ISSUE", conflicts_with_all = ["relation", "other"])]
+        duplicate_of: Option<String>,
+        /// For duplicates, move the issue to its team's canceled state without asking
+        #[arg(long)]
+        cancel: bool,
+        /// Output the created relation as JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Remove the relations between two issues
+    Unrelate {
+        /// Issue ID or identifier
+        id: String,
+        /// The other issue
+        other: String,
+        /// Only remove this kind of relation
+        #[arg(long = "type", value_enum, value_name = "RELATION")]
+        relation: Option<RelationKind>,
+    },
+    /// List an issue's inbound and outbound relations
+    Relations {
+        /// Issue ID or identifier
+        id: String,
+        /// Output as JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show who changed what on an issue, oldest first
+    History {
+        /// Issue ID or identifier
+        id: String,
+        /// Output the history entries as JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the direct sub-issues of an issue
+    Children {
+        /// Parent issue ID or identifier
+        id: String,
+        /// Output as JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show the full sub-issue hierarchy of an issue with progress counts
+    Tree {
+        /// Root issue ID or identifier
+        id: String,
+        /// Output as nested JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Move issues under a different parent, or detach them with --no-parent
+    Reparent {
+        /// Issue IDs or identifiers to move ("-" reads them from stdin)
+        #[arg(required = true)]
+        ids: Vec<String>,
+        /// New parent issue
+        #[arg(long, value_name = "ISSUE", required_unless_present = "no_parent")]
+        parent: Option<String>,
+        /// Make the issues top-level
+        #[arg(long, conflicts_with = "parent")]
+        no_parent: bool,
+        /// Output the updated issues as JSON (for scripts and agents)
+        #[arg(long)]
+        json: bool,
     },
     /// Add a comment to an issue
     Comment {
-        /// Issue ID or identifier (e.g. ENG-123)
-        id: String,
-        /// Comment body (reads from stdin if omitted in non-interactive mode)
-        #[arg(short, long)]
+        /// Issue IDs or identifiers (e.g. ENG-123; "-" reads them from stdin);
+        /// defaults to the issue named by the current git branch
+        ids: Vec<String>,
+        /// Comment body (opens $EDITOR, or reads stdin when piped, if omitted)
+        #[arg(short, long, conflicts_with = "body_file")]
         body: Option<String>,
+        /// Read the comment body from a file ("-" for stdin)
+        #[arg(long = "body-file", value_name = "FILE")]
+        body_file: Option<std::path::PathBuf>,
         /// Output created comment as JSON (for scripts and agents)
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
+}
+
+#[derive(Subcommand)]
+#[command(
+    after_help = "\x1b[1mExamples:\x1b[0m
+  linear comment list DIS-510              Show comments with their IDs
+  linear comment reply <comment-id> -b 'Agreed'
+  linear comment edit <comment-id>         Edit in $EDITOR
+  linear comment delete <comment-id> --yes
+  linear comment resolve <comment-id>"
+)]
+pub enum CommentCommands {
+    /// List comments on an issue, including their IDs
+    List {
+        /// Issue ID or identifier (e.g. ENG-123)
+        issue: String,
+        /// Output as JSON (for scripts and agents)
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        /// Print comment bodies as raw Markdown
         #[arg(long)]
+        raw: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
+    /// Reply to a comment thread
+    Reply {
+        /// Comment ID
+        id: String,
+        /// Reply body (opens $EDITOR, or reads stdin when piped, if omitted)
+        #[arg(short, long, conflicts_with = "body_file")]
+        body: Option<String>,
+        /// Read the reply body from a file ("-" for stdin)
+        #[arg(long = "body-file", value_name = "FILE")]
+        body_file: Option<std::path::PathBuf>,
+        /// Output created reply as JSON (for scripts and agents)
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
         json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
     },
+    /// Edit a comment
+    Edit {
+        /// Comment ID
+        id: String,
+        /// New body (opens $EDITOR, or reads stdin when piped, if omitted)
+        #[arg(short, long, conflicts_with = "body_file")]
+        body: Option<String>,
+        /// Read the new body from a file ("-" for stdin)
+        #[arg(long = "body-file", value_name = "FILE")]
+        body_file: Option<std::path::PathBuf>,
+        /// Output updated comment as JSON (for scripts and agents)
+        #[arg(long, conflicts_with_all = ["template", "template_file"])]
+        json: bool,
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
+    /// Delete a comment
+    #[command(alias = "rm")]
+    Delete {
+        /// Comment ID
+        id: String,
+        /// Do not ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Mark a comment thread as resolved
+    Resolve {
+        /// Comment ID
+        id: String,
+    },
+    /// Reopen a resolved comment thread
+    Unresolve {
+        /// Comment ID
+        id: String,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum RelationKind {
+    Blocks,
+    BlockedBy,
+    #[value(alias = "duplicates")]
+    DuplicateOf,
+    DuplicatedBy,
+    #[value(alias = "relates-to")]
+    Related,
+    Similar,
+}
+
+#[derive(clap::Args)]
+pub struct IssueTargets {
+    /// Issue IDs or identifiers (e.g. ENG-123 ENG-124)
+    #[arg(required = true, value_name = "ID")]
+    pub ids: Vec<String>,
+    /// Output per-issue results as JSON (for scripts and agents)
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Clone, Copy)]
+pub enum CommentLimit {
+    All,
+    None,
+    Count(usize),
+}
+
+impl std::str::FromStr for CommentLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "none" => Ok(Self::None),
+            n => n
+                .parse::<usize>()
+                .map(Self::Count)
+                .map_err(|_| format!("expected a number, \"all\" or \"none\", got '{s}'")),
+        }
+    }
 }
 
 /// Check if a string looks like a Linear issue identifier (e.g. ENG-123, DIS-510).
@@ -115,6 +483,19 @@ pub fn looks_like_issue_id(s: &str) -> bool {
         && number.chars().all(|c| c.is_ascii_digit())
 }
 
+#[derive(clap::Args)]
+pub struct TemplateArgs {
+    /// Render each result with a template, e.g. '{{identifier}}\t{{title | truncate 60}}'
+    ///
+    /// Fields use the same names as --json output. Filters: truncate N, upper,
+    /// lower, date [FORMAT|relative], default TEXT, join [SEP], color NAME.
+    #[arg(long, value_name = "TEMPLATE", conflicts_with = "template_file")]
+    pub template: Option<String>,
+    /// Read the output template from a file
+    #[arg(long = "template-file", value_name = "FILE", conflicts_with = "template")]
+    pub template_file: Option<std::path::PathBuf>,
+}
+
 #[derive(clap::Args)]
 pub struct AuthArgs {
     /// Read API key from file (recommended for scripts)
@@ -147,8 +528,70 @@ pub struct ApiArgs {
     pub variables_file: Option<std::path::PathBuf>,
 }
 
+/// Workflow state categories shared by every team.
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum StateType {
+    Triage,
+    Backlog,
+    Unstarted,
+    Started,
+    Completed,
+    #[value(alias = "cancelled")]
+    Canceled,
+}
+
+impl StateType {
+    /// The `WorkflowState.type` value in Linear's API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StateType::Triage => "triage",
+            StateType::Backlog => "backlog",
+            StateType::Unstarted => "unstarted",
+            StateType::Started => "started",
+            StateType::Completed => "completed",
+            StateType::Canceled => "canceled",
+        }
+    }
+}
+
+/// Issues for the `done`, `cancel`, `backlog` and `triage` shortcuts.
 #[derive(clap::Args)]
-pub struct ListArgs {
+pub struct TransitionArgs {
+    /// Issue IDs or identifiers ("-" reads them from stdin)
+    #[arg(required = true, value_name = "ID")]
+    pub ids: Vec<String>,
+    /// Output the updated issues as JSON
+    #[arg(long, conflicts_with_all = ["template", "template_file"])]
+    pub json: bool,
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+#[derive(clap::Args)]
+pub struct StartArgs {
+    /// Issue IDs or identifiers ("-" reads them from stdin); a branch is only
+    /// checked out for a single issue
+    #[arg(required = true, value_name = "ID")]
+    pub ids: Vec<String>,
+    /// Print the issues' branch names instead of checking one out
+    #[arg(long)]
+    pub no_checkout: bool,
+    /// Output the updated issues as JSON
+    #[arg(long, conflicts_with_all = ["template", "template_file"])]
+    pub json: bool,
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum ListFormat {
+    Table,
+    Ids,
+}
+
+/// Issue filters shared by `issue list` and `issue bulk-update`.
+#[derive(clap::Args)]
+pub struct IssueFilterArgs {
     /// Filter by team key (e.g. ENG)
     #[arg(long)]
     pub team: Option<String>,
@@ -176,10 +619,107 @@ pub struct ListArgs {
     /// Include completed and canceled issues
     #[arg(long)]
     pub all: bool,
+    /// Only archived and trashed issues
+    #[arg(long)]
+    pub archived: bool,
+}
+
+#[derive(clap::Args)]
+pub struct ListArgs {
+    #[command(flatten)]
+    pub filter: IssueFilterArgs,
     /// Maximum number of issues to return
     #[arg(long, default_value = "50")]
     pub limit: i32,
     /// Output as JSON (for scripts and agents)
+    #[arg(long, conflicts_with_all = ["template", "template_file"])]
+    pub json: bool,
+    /// Output format; `ids` prints one identifier per line for piping into other commands
+    #[arg(
+        long,
+        value_enum,
+        default_value = "table",
+        conflicts_with_all = ["json", "template", "template_file"]
+    )]
+    pub format: ListFormat,
+    #[command(flatten)]
+    pub template: TemplateArgs,
+}
+
+#[derive(clap::Args)]
+pub struct BulkUpdateArgs {
+    /// Issue IDs or identifiers ("-" reads them from stdin); omit to select by filters
+    #[arg(conflicts_with_all = [
+        "team", "state", "assignee", "mine", "all_assignees", "priority", "label", "project",
+        "all", "archived",
+    ])]
+    pub ids: Vec<String>,
+    #[command(flatten)]
+    pub filter: IssueFilterArgs,
+    /// Maximum number of issues selected by filters; if more match, nothing is updated
+    #[arg(long, default_value = "250")]
+    pub limit: usize,
+    /// New state name, looked up in each issue's team
+    #[arg(long = "set-state", value_name = "STATE")]
+    pub set_state: Option<String>,
+    /// New state by type (e.g. completed), looked up in each issue's team
+    #[arg(
+        long = "set-state-type",
+        value_enum,
+        value_name = "TYPE",
+        conflicts_with = "set_state"
+    )]
+    pub set_state_type: Option<StateType>,
+    /// New assignee (display name/name/email, "me", or "" to unassign)
+    #[arg(long = "set-assignee", value_name = "USER")]
+    pub set_assignee: Option<String>,
+    /// New priority (0=none, 1=urgent, 2=high, 3=medium, 4=low)
+    #[arg(long = "set-priority", value_name = "PRIORITY")]
+    pub set_priority: Option<i32>,
+    /// Add a label (repeatable)
+    #[arg(long = "add-label", value_name = "LABEL")]
+    pub add_label: Vec<String>,
+    /// Remove a label (repeatable)
+    #[arg(long = "remove-label", value_name = "LABEL")]
+    pub remove_label: Vec<String>,
+    /// Move to a project (name)
+    #[arg(long = "set-project", value_name = "PROJECT", conflicts_with = "no_project")]
+    pub set_project: Option<String>,
+    /// Remove from their project
+    #[arg(long)]
+    pub no_project: bool,
+    /// Cycle number, name, or current/next/previous, looked up in each issue's team
+    #[arg(long = "set-cycle", value_name = "CYCLE", conflicts_with = "no_cycle")]
+    pub set_cycle: Option<String>,
+    /// Remove from their cycle
+    #[arg(long)]
+    pub no_cycle: bool,
+    /// Estimate in points
+    #[arg(
+        long = "set-estimate",
+        value_name = "POINTS",
+        conflicts_with = "no_estimate"
+    )]
+    pub set_estimate: Option<i64>,
+    /// Clear the estimate
+    #[arg(long)]
+    pub no_estimate: bool,
+    /// Due date (YYYY-MM-DD)
+    #[arg(long = "set-due", value_name = "DATE", conflicts_with = "no_due")]
+    pub set_due: Option<chrono::NaiveDate>,
+    /// Clear the due date
+    #[arg(long)]
+    pub no_due: bool,
+    /// Number of updates sent at once (1-20)
+    #[arg(long, default_value = "5", value_parser = clap::value_parser!(u8).range(1..=20))]
+    pub concurrency: u8,
+    /// Show what would change without updating anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Apply without asking for confirmation
+    #[arg(short, long)]
+    pub yes: bool,
+    /// Output per-issue results as JSON (for scripts and agents)
     #[arg(long)]
     pub json: bool,
 }
@@ -192,39 +732,112 @@ pub struct CreateArgs {
     /// Issue title
     #[arg(long)]
     pub title: Option<String>,
-    /// Issue description
-    #[arg(long)]
+    /// Issue description (opens $EDITOR if omitted in a terminal; pass "" to skip)
+    #[arg(long, conflicts_with = "description_file")]
     pub description: Option<String>,
+    /// Read the description from a Markdown file ("-" for stdin)
+    #[arg(long = "description-file", value_name = "FILE")]
+    pub description_file: Option<std::path::PathBuf>,
     /// Priority (0=none, 1=urgent, 2=high, 3=medium, 4=low)
     #[arg(long)]
     pub priority: Option<i32>,
     /// State name
     #[arg(long)]
     pub state: Option<String>,
+    /// Initial state by type (e.g. backlog), whatever the team calls it
+    #[arg(long, value_enum, value_name = "TYPE", conflicts_with = "state")]
+    pub state_type: Option<StateType>,
     /// Assignee (display name/name/email, or "me")
     #[arg(long)]
     pub assignee: Option<String>,
-    /// Label name
+    /// Label name (repeatable)
+    #[arg(long, value_name = "LABEL")]
+    pub label: Vec<String>,
+    /// Project name
     #[arg(long)]
-    pub label: Option<String>,
+    pub project: Option<String>,
+    /// Project milestone name (needs a project)
+    #[arg(long)]
+    pub milestone: Option<String>,
+    /// Cycle number, name, or current/next/previous
+    #[arg(long)]
+    pub cycle: Option<String>,
+    /// Estimate in points
+    #[arg(long)]
+    pub estimate: Option<i64>,
+    /// Due date (YYYY-MM-DD)
+    #[arg(long, value_name = "DATE")]
+    pub due: Option<chrono::NaiveDate>,
+    /// Parent issue identifier (e.g. ENG-123)
+    #[arg(long, value_name = "ISSUE")]
+    pub parent: Option<String>,
+    /// Subscribe a user (display name/name/email, or "me"; repeatable)
+    #[arg(long, value_name = "USER")]
+    pub subscriber: Vec<String>,
+    /// Position in manual ordering
+    #[arg(long, value_name = "NUMBER")]
+    pub sort_order: Option<f64>,
+    /// Create from a Markdown file with TOML front matter, or from every .md file in a directory
+    ///
+    /// Front matter keys: team, title, state, priority, assignee, labels,
+    /// project, parent, estimate, due. The body becomes the description; a
+    /// leading "# Heading" is used as the title when `title` is not set.
+    /// Flags given on the command line act as defaults.
+    #[arg(
+        long = "from-file",
+        value_name = "PATH",
+        conflicts_with_all = ["title", "description", "description_file"]
+    )]
+    pub from_file: Option<std::path::PathBuf>,
+    /// With --from-file, resolve every file without creating anything
+    #[arg(long, requires = "from_file")]
+    pub dry_run: bool,
+    /// Make the create safe to retry: the same key always creates (or returns) the same issue
+    ///
+    /// The key is turned into a deterministic issue ID. With --from-file it is
+    /// combined with each file's name inside the --from-file directory.
+    #[arg(long, value_name = "KEY", conflicts_with = "id")]
+    pub idempotency_key: Option<String>,
+    /// Client-generated issue UUID; if it already exists, that issue is returned
+    #[arg(long, value_name = "UUID", conflicts_with = "from_file")]
+    pub id: Option<uuid::Uuid>,
+    /// Output the created issue as JSON
+    #[arg(long, conflicts_with_all = ["template", "template_file"])]
+    pub json: bool,
+    #[command(flatten)]
+    pub template: TemplateArgs,
 }
 
 #[derive(clap::Args)]
 pub struct UpdateArgs {
-    /// Issue ID or identifier
-    pub id: String,
+    /// Issue IDs or identifiers ("-" reads them from stdin); defaults to the
+    /// issue named by the current git branch
+    pub ids: Vec<String>,
     /// New title
     #[arg(long)]
     pub title: Option<String>,
     /// New description
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["description_file", "edit_description"])]
     pub description: Option<String>,
+    /// Read the new description from a Markdown file ("-" for stdin)
+    #[arg(
+        long = "description-file",
+        value_name = "FILE",
+        conflicts_with = "edit_description"
+    )]
+    pub description_file: Option<std::path::PathBuf>,
+    /// Edit the current description in $EDITOR
+    #[arg(long)]
+    pub edit_description: bool,
     /// New priority
     #[arg(long)]
     pub priority: Option<i32>,
     /// New state name
     #[arg(long)]
     pub state: Option<String>,
+    /// New state by type (e.g. completed), whatever the team calls it
+    #[arg(long, value_enum, value_name = "TYPE", conflicts_with = "state")]
+    pub state_type: Option<StateType>,
     /// New assignee (display name/name/email, "me", or "" to unassign)
     #[arg(long)]
     pub assignee: Option<String>,
@@ -237,10 +850,60 @@ pub struct UpdateArgs {
     /// Remove all labels
     #[arg(long, conflicts_with_all = ["add_label", "remove_label"])]
     pub clear_labels: bool,
+    /// Move to a project (name)
+    #[arg(long, conflicts_with = "no_project")]
+    pub project: Option<String>,
+    /// Remove from its project
+    #[arg(long)]
+    pub no_project: bool,
+    /// Project milestone name (in --project, or the issue's current project)
+    #[arg(long, conflicts_with_all = ["no_milestone", "no_project"])]
+    pub milestone: Option<String>,
+    /// Remove from its project milestone
+    #[arg(long)]
+    pub no_milestone: bool,
+    /// Cycle number, name, or current/next/previous
+    #[arg(long, conflicts_with = "no_cycle")]
+    pub cycle: Option<String>,
+    /// Remove from its cycle
+    #[arg(long)]
+    pub no_cycle: bool,
+    /// Estimate in points
+    #[arg(long, conflicts_with = "no_estimate")]
+    pub estimate: Option<i64>,
+    /// Clear the estimate
+    #[arg(long)]
+    pub no_estimate: bool,
+    /// Due date (YYYY-MM-DD)
+    #[arg(long, value_name = "DATE", conflicts_with = "no_due")]
+    pub due: Option<chrono::NaiveDate>,
+    /// Clear the due date
+    #[arg(long)]
+    pub no_due: bool,
+    /// Parent issue identifier (e.g. ENG-123)
+    #[arg(long, value_name = "ISSUE", conflicts_with = "no_parent")]
+    pub parent: Option<String>,
+    /// Detach from its parent issue
+    #[arg(long)]
+    pub no_parent: bool,
+    /// Subscribe a user (display name/name/email, or "me"; repeatable)
+    #[arg(long, value_name = "USER")]
+    pub subscriber: Vec<String>,
+    /// Position in manual ordering
+    #[arg(long, value_name = "NUMBER")]
+    pub sort_order: Option<f64>,
+    /// Output the updated issue as JSON
+    #[arg(long, conflicts_with_all = ["template", "template_file"])]
+    pub json: bool,
+    #[command(flatten)]
+    pub template: TemplateArgs,
 }
 
 #[derive(Subcommand)]
 pub enum TeamCommands {
     /// List all teams
-    List,
+    List {
+        #[command(flatten)]
+        template: TemplateArgs,
+    },
 }
@@ -0,0 +1,45 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::{CommentDeleteResponse, CommentResponse};
+use crate::api::{mutations, queries};
+use crate::config;
+use crate::interactive;
+use crate::output;
+
+const PREVIEW_WIDTH: usize = 60;
+
+pub async fn run(id: String, yes: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+
+    if !yes {
+        let resp: CommentResponse = client.query(queries::COMMENT, json!({ "id": id })).await?;
+        let comment = resp.comment;
+        let author = comment
+            .user
+            .as_ref()
+            .and_then(|u| u.display_name.as_deref().or(u.name.as_deref()))
+            .unwrap_or("Unknown");
+        let preview = comment
+            .body
+            .as_deref()
+            .and_then(|b| b.lines().find(|l| !l.trim().is_empty()))
+            .map(|l| output::truncate_visible(l.trim(), PREVIEW_WIDTH))
+            .unwrap_or_default();
+        let prompt = format!("Delete comment by {author}: \"{preview}\"?");
+        if !interactive::confirm(&prompt, yes)? {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let resp: CommentDeleteResponse = client
+        .query(mutations::COMMENT_DELETE, json!({ "id": id }))
+        .await?;
+    if !resp.comment_delete.success {
+        bail!("Failed to delete comment");
+    }
+    println!("Comment deleted.");
+    Ok(())
+}
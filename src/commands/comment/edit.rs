@@ -0,0 +1,51 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::api::client::LinearClient;
+use crate::api::types::{CommentResponse, CommentUpdateResponse};
+use crate::api::{mutations, queries};
+use crate::cli::TemplateArgs;
+use crate::config;
+use crate::template;
+
+pub async fn run(
+    id: String,
+    body: Option<String>,
+    body_file: Option<PathBuf>,
+    json_output: bool,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let client = LinearClient::new(config::api_key()?);
+
+    let body = if body.is_some() || body_file.is_some() {
+        super::read_body(body, body_file.as_deref(), "")?
+    } else {
+        let resp: CommentResponse = client.query(queries::COMMENT, json!({ "id": id })).await?;
+        let current = resp.comment.body.unwrap_or_default();
+        let body = super::read_body(None, None, &current)?;
+        if body.trim_end() == current.trim_end() {
+            println!("No changes.");
+            return Ok(());
+        }
+        body
+    };
+
+    let resp: CommentUpdateResponse = client
+        .query(
+            mutations::COMMENT_UPDATE,
+            json!({ "id": id, "input": { "body": body } }),
+        )
+        .await?;
+
+    if !resp.comment_update.success {
+        bail!("Failed to update comment");
+    }
+    super::print_comment(
+        resp.comment_update.comment.as_ref(),
+        json_output,
+        template.as_ref(),
+        "Comment updated",
+    )
+}
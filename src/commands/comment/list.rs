@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+use crate::api::client::LinearClient;
+use crate::api::paginate;
+use crate::cli::TemplateArgs;
+use crate::config;
+use crate::output;
+use crate::template;
+
+pub async fn run(issue: String, json: bool, raw: bool, template_args: TemplateArgs) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let client = LinearClient::new(config::api_key()?);
+    let comments = paginate::issue_comments(&client, &issue, None).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&comments)?);
+    } else if let Some(template) = &template {
+        template::print_each(template, &comments)?;
+    } else {
+        output::comment_list(&comments, raw);
+    }
+    Ok(())
+}
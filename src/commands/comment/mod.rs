@@ -0,0 +1,55 @@
+pub mod delete;
+pub mod edit;
+pub mod list;
+pub mod reply;
+pub mod resolve;
+
+use anyhow::{Result, bail};
+use std::io::Read;
+use std::path::Path;
+
+use crate::api::types::Comment;
+use crate::editor;
+use crate::interactive;
+use crate::template::{self, Template};
+
+/// Body from `--body`, `--body-file`, piped stdin, or `$EDITOR` prefilled with `initial`.
+pub fn read_body(body: Option<String>, body_file: Option<&Path>, initial: &str) -> Result<String> {
+    let body = match (body, body_file) {
+        (Some(body), _) => body,
+        (None, Some(path)) => interactive::read_file(path)?,
+        (None, None) if !interactive::is_tty() => {
+            let mut body = String::new();
+            std::io::stdin().read_to_string(&mut body)?;
+            body
+        }
+        (None, None) => {
+            return editor::compose(initial, "Write the comment in Markdown.", "comment");
+        }
+    };
+    if body.trim().is_empty() {
+        bail!("Comment body cannot be empty");
+    }
+    Ok(body)
+}
+
+/// Print a comment returned by a mutation as JSON, through a template, or as `message`.
+fn print_comment(
+    comment: Option<&Comment>,
+    json: bool,
+    template: Option<&Template>,
+    message: &str,
+) -> Result<()> {
+    let Some(comment) = comment else {
+        println!("{message}");
+        return Ok(());
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(comment)?);
+    } else if let Some(template) = template {
+        template::print_one(template, comment)?;
+    } else {
+        println!("{message} ({})", comment.id);
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::api::client::LinearClient;
+use crate::api::types::{CommentCreateResponse, CommentResponse};
+use crate::api::{mutations, queries};
+use crate::cli::TemplateArgs;
+use crate::config;
+use crate::template;
+
+pub async fn run(
+    id: String,
+    body: Option<String>,
+    body_file: Option<PathBuf>,
+    json_output: bool,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let client = LinearClient::new(config::api_key()?);
+    let resp: CommentResponse = client.query(queries::COMMENT, json!({ "id": id })).await?;
+    let target = resp.comment;
+    let Some(issue) = &target.issue else {
+        bail!("Comment {id} is not attached to an issue");
+    };
+
+    let body = super::read_body(body, body_file.as_deref(), "")?;
+
+    // Threads are one level deep: replying to a reply continues its thread.
+    let parent_id = target.parent.as_ref().map_or(&target.id, |p| &p.id);
+    let resp: CommentCreateResponse = client
+        .query(
+            mutations::COMMENT_CREATE,
+            json!({ "input": { "issueId": issue.id, "parentId": parent_id, "body": body } }),
+        )
+        .await?;
+
+    if !resp.comment_create.success {
+        bail!("Failed to create reply");
+    }
+    let issue_ref = issue.identifier.as_deref().unwrap_or(&issue.id);
+    super::print_comment(
+        resp.comment_create.comment.as_ref(),
+        json_output,
+        template.as_ref(),
+        &format!("Reply added to {issue_ref}"),
+    )
+}
@@ -0,0 +1,30 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::mutations;
+use crate::api::types::{CommentResolveResponse, CommentUnresolveResponse};
+use crate::config;
+
+/// Resolve (`resolved = true`) or reopen a comment thread.
+pub async fn run(id: String, resolved: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let vars = json!({ "id": id });
+
+    let success = if resolved {
+        let resp: CommentResolveResponse = client.query(mutations::COMMENT_RESOLVE, vars).await?;
+        resp.comment_resolve.success
+    } else {
+        let resp: CommentUnresolveResponse =
+            client.query(mutations::COMMENT_UNRESOLVE, vars).await?;
+        resp.comment_unresolve.success
+    };
+
+    match (success, resolved) {
+        (true, true) => println!("Comment resolved."),
+        (true, false) => println!("Comment reopened."),
+        (false, true) => bail!("Failed to resolve comment"),
+        (false, false) => bail!("Failed to unresolve comment"),
+    }
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::{Value, json};
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, queries};
+use crate::cli::IssueTargets;
+use crate::config;
+use crate::interactive;
+
+#[derive(Clone, Copy)]
+pub enum Action {
+    Archive,
+    Unarchive,
+    Delete { permanent: bool },
+    Restore,
+}
+
+impl Action {
+    fn verb(self) -> &'static str {
+        match self {
+            Action::Archive => "Archive",
+            Action::Unarchive => "Unarchive",
+            Action::Delete { permanent: false } => "Move to trash",
+            Action::Delete { permanent: true } => "Permanently delete",
+            Action::Restore => "Restore",
+        }
+    }
+
+    fn done(self) -> &'static str {
+        match self {
+            Action::Archive => "Archived",
+            Action::Unarchive => "Unarchived",
+            Action::Delete { permanent: false } => "Moved to trash",
+            Action::Delete { permanent: true } => "Permanently deleted",
+            Action::Restore => "Restored",
+        }
+    }
+
+    async fn apply(self, client: &LinearClient, id: &str) -> Result<ArchiveResult> {
+        let result = match self {
+            Action::Archive => {
+                let resp: IssueArchiveResponse = client
+                    .query(mutations::ISSUE_ARCHIVE, json!({ "id": id }))
+                    .await?;
+                resp.issue_archive
+            }
+            // Linear restores trashed issues through the same mutation.
+            Action::Unarchive | Action::Restore => {
+                let resp: IssueUnarchiveResponse = client
+                    .query(mutations::ISSUE_UNARCHIVE, json!({ "id": id }))
+                    .await?;
+                resp.issue_unarchive
+            }
+            Action::Delete { permanent } => {
+                let resp: IssueDeleteResponse = client
+                    .query(
+                        mutations::ISSUE_DELETE,
+                        json!({ "id": id, "permanentlyDelete": permanent }),
+                    )
+                    .await?;
+                resp.issue_delete
+            }
+        };
+        Ok(result)
+    }
+}
+
+/// Archive, unarchive, delete or restore each issue, reporting per-issue results.
+pub async fn run(action: Action, target: IssueTargets, yes: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+
+    if !yes {
+        for id in &target.ids {
+            let resp: IssueResponse = client
+                .query(queries::ISSUE_REF, json!({ "id": id }))
+                .await?;
+            let issue = resp.issue;
+            let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+            let title = issue.title.as_deref().unwrap_or("");
+            eprintln!("  {}  {title}", identifier.bold());
+        }
+        let prompt = format!("{} {} issue(s)?", action.verb(), target.ids.len());
+        if !interactive::confirm(&prompt, false)? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut failed = 0;
+    for id in &target.ids {
+        let outcome = match action.apply(&client, id).await {
+            Ok(result) if result.success => Ok(result.entity),
+            Ok(_) => Err(format!("{} failed", action.verb())),
+            Err(err) => Err(format!("{err:#}")),
+        };
+        match outcome {
+            Ok(issue) => {
+                if !target.json {
+                    let identifier = issue
+                        .as_ref()
+                        .and_then(|i| i.identifier.as_deref())
+                        .unwrap_or(id);
+                    println!("{} {identifier}", action.done());
+                }
+                results.push(json!({ "id": id, "success": true, "issue": issue }));
+            }
+            Err(error) => {
+                failed += 1;
+                if !target.json {
+                    eprintln!("{} {id}: {error}", "Failed".red().bold());
+                }
+                results.push(json!({ "id": id, "success": false, "error": error }));
+            }
+        }
+    }
+
+    if target.json {
+        println!("{}", serde_json::to_string_pretty(&Value::Array(results))?);
+    }
+    if failed > 0 {
+        bail!("{failed} of {} issue(s) failed", target.ids.len());
+    }
+    Ok(())
+}
@@ -1,12 +1,28 @@
-use anyhow::Result;
-use serde_json::json;
+use anyhow::{Result, bail};
+use serde_json::{Value, json};
 
 use crate::api::client::LinearClient;
 use crate::api::types::IssueUpdateResponse;
 use crate::api::{mutations, resolve};
+use crate::cli::TemplateArgs;
 use crate::config;
+use crate::template::{self, Template};
 
-pub async fn run(id: String, user: Option<String>) -> Result<()> {
+pub async fn run(
+    args: Vec<String>,
+    unassign: bool,
+    json: bool,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let (ids, user) = if unassign {
+        (args, None)
+    } else {
+        super::split_value(args, "user")?
+    };
+    if user.is_none() && !unassign {
+        bail!("Pass the user to assign, or --unassign");
+    }
     let client = LinearClient::new(config::api_key()?);
 
     let (input, action) = match &user {
@@ -17,6 +33,21 @@ pub async fn run(id: String, user: Option<String>) -> Result<()> {
         None => (json!({ "assigneeId": null }), "Unassigned".to_string()),
     };
 
+    let ids = super::read_ids(ids)?;
+    super::for_each_id(ids, |id| {
+        assign(&client, id, &input, &action, json, template.as_ref())
+    })
+    .await
+}
+
+async fn assign(
+    client: &LinearClient,
+    id: String,
+    input: &Value,
+    action: &str,
+    json: bool,
+    template: Option<&Template>,
+) -> Result<()> {
     let resp: IssueUpdateResponse = client
         .query(mutations::ISSUE_UPDATE, json!({ "id": id, "input": input }))
         .await?;
@@ -24,10 +55,11 @@ pub async fn run(id: String, user: Option<String>) -> Result<()> {
     if resp.issue_update.success {
         if let Some(issue) = resp.issue_update.issue {
             let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
-            println!("{identifier}: {action}");
+            let message = format!("{identifier}: {action}");
+            super::print_issue(&issue, json, template, &message)?;
         }
     } else {
-        anyhow::bail!("Failed to update issue");
+        bail!("Failed to update issue");
     }
 
     Ok(())
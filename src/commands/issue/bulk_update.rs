@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::{Value, json};
+use tokio::task::JoinSet;
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, paginate, queries, resolve};
+use crate::cli::BulkUpdateArgs;
+use crate::commands::issue::{list, state};
+use crate::config;
+use crate::interactive;
+use crate::output;
+use crate::pager;
+
+/// An issue together with the `IssueUpdateInput` it needs and a readable
+/// description of each change.
+struct Plan<'a> {
+    issue: &'a Issue,
+    input: Value,
+    changes: Vec<String>,
+}
+
+/// IDs resolved once for the whole batch; team-specific states and cycles
+/// are cached per team as issues are planned.
+struct Resolved {
+    assignee: Option<Option<String>>,
+    project: Option<Option<String>>,
+    add_labels: Vec<String>,
+    remove_labels: Vec<String>,
+    states: HashMap<String, (String, String)>,
+    cycles: HashMap<String, String>,
+}
+
+pub async fn run(args: BulkUpdateArgs) -> Result<()> {
+    if !has_changes(&args) {
+        bail!(
+            "No changes specified. Use --set-state, --set-state-type, --set-assignee, --set-priority, --add-label, --remove-label, --set-project, --set-cycle, --set-estimate, --set-due, or see --help."
+        );
+    }
+    let client = LinearClient::new(config::api_key()?);
+    let issues = select(&client, &args).await?;
+    if issues.is_empty() {
+        println!("No issues matched.");
+        return Ok(());
+    }
+
+    let mut resolved = resolve_shared(&client, &args).await?;
+    let mut plans = Vec::new();
+    for issue in &issues {
+        let plan = plan(&client, &args, &mut resolved, issue).await?;
+        if !plan.changes.is_empty() {
+            plans.push(plan);
+        }
+    }
+    let unchanged = issues.len() - plans.len();
+    if plans.is_empty() {
+        println!(
+            "All {} matching issue(s) are already up to date.",
+            issues.len()
+        );
+        return Ok(());
+    }
+
+    if args.dry_run && args.json {
+        let preview: Vec<Value> = plans
+            .iter()
+            .map(|p| {
+                json!({
+                    "id": p.issue.id,
+                    "identifier": p.issue.identifier,
+                    "input": p.input,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&preview)?);
+        return Ok(());
+    }
+    let rows: Vec<(&Issue, Vec<String>)> =
+        plans.iter().map(|p| (p.issue, p.changes.clone())).collect();
+    if !args.json {
+        pager::page(&output::change_table(&rows));
+        if unchanged > 0 {
+            println!("{unchanged} matching issue(s) already up to date.");
+        }
+    } else if !args.dry_run && !args.yes {
+        // Keep stdout for the JSON results, but never ask blind.
+        eprintln!("{}", output::change_table(&rows));
+    }
+    if args.dry_run {
+        return Ok(());
+    }
+
+    let prompt = format!("Update {} issue(s)?", plans.len());
+    if !interactive::confirm(&prompt, args.yes)? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    apply(&client, &plans, usize::from(args.concurrency), args.json).await
+}
+
+fn has_changes(args: &BulkUpdateArgs) -> bool {
+    args.set_state.is_some()
+        || args.set_state_type.is_some()
+        || args.set_assignee.is_some()
+        || args.set_priority.is_some()
+        || !args.add_label.is_empty()
+        || !args.remove_label.is_empty()
+        || args.set_project.is_some()
+        || args.no_project
+        || args.set_cycle.is_some()
+        || args.no_cycle
+        || args.set_estimate.is_some()
+        || args.no_estimate
+        || args.set_due.is_some()
+        || args.no_due
+}
+
+/// Issues named on the command line (or stdin), otherwise those matching the filters.
+async fn select(client: &LinearClient, args: &BulkUpdateArgs) -> Result<Vec<Issue>> {
+    if args.ids.is_empty() {
+        let filter = list::build_filter(client, &args.filter).await?;
+        // Fetch one extra to tell "exactly --limit" from "more than --limit".
+        let issues = paginate::collect(
+            client,
+            queries::ISSUES,
+            json!({ "filter": filter, "includeArchived": args.filter.archived }),
+            Some(args.limit + 1),
+            |resp: IssuesResponse| resp.issues,
+        )
+        .await?;
+        if issues.len() > args.limit {
+            bail!(
+                "More than {} issues match these filters. Narrow the filters or raise --limit to update them all.",
+                args.limit
+            );
+        }
+        return Ok(issues);
+    }
+
+    let ids = super::read_ids(args.ids.clone())?;
+
+    // Identifiers are matched by team key and number so the whole list is one query.
+    let clauses: Vec<Value> = ids
+        .iter()
+        .map(|id| match id.split_once('-') {
+            Some((key, number)) if number.parse::<u64>().is_ok() => json!({
+                "team": { "key": { "eqIgnoreCase": key } },
+                "number": { "eq": number.parse::<u64>().unwrap_or_default() },
+            }),
+            _ => json!({ "id": { "eq": id } }),
+        })
+        .collect();
+    let issues = paginate::collect(
+        client,
+        queries::ISSUES,
+        json!({ "filter": { "or": clauses }, "includeArchived": true }),
+        Some(ids.len()),
+        |resp: IssuesResponse| resp.issues,
+    )
+    .await?;
+
+    let missing: Vec<&str> = ids
+        .iter()
+        .filter(|id| {
+            !issues.iter().any(|issue| {
+                issue.id == **id
+                    || issue
+                        .identifier
+                        .as_deref()
+                        .is_some_and(|i| i.eq_ignore_ascii_case(id))
+            })
+        })
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        bail!("Issue(s) not found: {}", missing.join(", "));
+    }
+    Ok(issues)
+}
+
+async fn resolve_shared(client: &LinearClient, args: &BulkUpdateArgs) -> Result<Resolved> {
+    let assignee = match args.set_assignee.as_deref() {
+        Some("") => Some(None),
+        Some(user) => Some(Some(resolve::user_id(client, user).await?)),
+        None => None,
+    };
+    let project = match &args.set_project {
+        Some(project) => Some(Some(resolve::project_id(client, project).await?)),
+        None if args.no_project => Some(None),
+        None => None,
+    };
+    let mut add_labels = Vec::new();
+    for label in &args.add_label {
+        add_labels.push(resolve::label_id(client, label).await?);
+    }
+    let mut remove_labels = Vec::new();
+    for label in &args.remove_label {
+        remove_labels.push(resolve::label_id(client, label).await?);
+    }
+    Ok(Resolved {
+        assignee,
+        project,
+        add_labels,
+        remove_labels,
+        states: HashMap::new(),
+        cycles: HashMap::new(),
+    })
+}
+
+/// Work out the update for one issue, skipping fields that already have the new value.
+async fn plan<'a>(
+    client: &LinearClient,
+    args: &BulkUpdateArgs,
+    resolved: &mut Resolved,
+    issue: &'a Issue,
+) -> Result<Plan<'a>> {
+    let mut input = json!({});
+    let mut changes = Vec::new();
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let team_id = issue.team.as_ref().map(|t| t.id.as_str());
+    let team_id = || team_id.ok_or_else(|| anyhow::anyhow!("{identifier} has no team"));
+
+    if args.set_state.is_some() || args.set_state_type.is_some() {
+        let team_id = team_id()?;
+        let (state_id, state_name) = match resolved.states.get(team_id) {
+            Some(state) => state.clone(),
+            None => {
+                let state = match (&args.set_state, args.set_state_type) {
+                    (Some(name), _) => (
+                        resolve::state_id(client, team_id, name).await?,
+                        name.clone(),
+                    ),
+                    (None, Some(state_type)) => {
+                        let state = state::state_of_type(client, team_id, state_type).await?;
+                        (state.id, state.name.unwrap_or_default())
+                    }
+                    (None, None) => unreachable!(),
+                };
+                resolved.states.insert(team_id.to_string(), state.clone());
+                state
+            }
+        };
+        let current = issue.state.as_ref();
+        if current.map(|s| s.id.as_str()) != Some(state_id.as_str()) {
+            input["stateId"] = json!(state_id);
+            let old = current.and_then(|s| s.name.as_deref()).unwrap_or("");
+            changes.push(change("state", old, &state_name));
+        }
+    }
+
+    if let Some(assignee) = &resolved.assignee {
+        let current = issue.assignee.as_ref();
+        if current.map(|u| &u.id) != assignee.as_ref() {
+            input["assigneeId"] = json!(assignee);
+            let old = current
+                .and_then(|u| u.display_name.as_deref().or(u.name.as_deref()))
+                .unwrap_or("");
+            let new = args.set_assignee.as_deref().unwrap_or("");
+            changes.push(change("assignee", old, new));
+        }
+    }
+
+    if let Some(priority) = args.set_priority
+        && issue.priority.unwrap_or(0) != priority
+    {
+        input["priority"] = json!(priority);
+        let old = output::priority_label(issue.priority.unwrap_or(0));
+        changes.push(change("priority", old, output::priority_label(priority)));
+    }
+
+    if !resolved.add_labels.is_empty() || !resolved.remove_labels.is_empty() {
+        let current: Vec<String> = issue
+            .labels
+            .as_ref()
+            .map(|c| c.nodes.iter().map(|l| l.id.clone()).collect())
+            .unwrap_or_default();
+        let mut label_ids = current.clone();
+        let mut parts = Vec::new();
+        for (id, name) in resolved.add_labels.iter().zip(&args.add_label) {
+            if !label_ids.contains(id) {
+                label_ids.push(id.clone());
+                parts.push(format!("+{name}").green().to_string());
+            }
+        }
+        for (id, name) in resolved.remove_labels.iter().zip(&args.remove_label) {
+            if label_ids.contains(id) {
+                label_ids.retain(|l| l != id);
+                parts.push(format!("-{name}").red().to_string());
+            }
+        }
+        if label_ids != current {
+            input["labelIds"] = json!(label_ids);
+            changes.push(format!("labels {}", parts.join(" ")));
+        }
+    }
+
+    if let Some(project) = &resolved.project {
+        let current = issue.project.as_ref();
+        if current.map(|p| &p.id) != project.as_ref() {
+            input["projectId"] = json!(project);
+            let old = current.and_then(|p| p.name.as_deref()).unwrap_or("");
+            let new = args.set_project.as_deref().unwrap_or("");
+            changes.push(change("project", old, new));
+        }
+    }
+
+    let current_cycle = issue.cycle.as_ref();
+    let old_cycle = current_cycle
+        .and_then(|c| c.number)
+        .map(|n| format!("{n:.0}"))
+        .unwrap_or_default();
+    if let Some(cycle) = &args.set_cycle {
+        let team_id = team_id()?;
+        let cycle_id = match resolved.cycles.get(team_id) {
+            Some(id) => id.clone(),
+            None => {
+                let id = resolve::cycle_id(client, team_id, cycle).await?;
+                resolved.cycles.insert(team_id.to_string(), id.clone());
+                id
+            }
+        };
+        if current_cycle.map(|c| c.id.as_str()) != Some(cycle_id.as_str()) {
+            input["cycleId"] = json!(cycle_id);
+            changes.push(change("cycle", &old_cycle, cycle));
+        }
+    } else if args.no_cycle && current_cycle.is_some() {
+        input["cycleId"] = Value::Null;
+        changes.push(change("cycle", &old_cycle, ""));
+    }
+
+    let old_estimate = issue.estimate.map(|e| e.to_string()).unwrap_or_default();
+    if let Some(estimate) = args.set_estimate
+        && issue.estimate != Some(estimate as f64)
+    {
+        input["estimate"] = json!(estimate);
+        changes.push(change("estimate", &old_estimate, &estimate.to_string()));
+    } else if args.no_estimate && issue.estimate.is_some() {
+        input["estimate"] = Value::Null;
+        changes.push(change("estimate", &old_estimate, ""));
+    }
+
+    let old_due = issue.due_date.as_deref().unwrap_or("");
+    if let Some(due) = args.set_due.map(|d| d.to_string())
+        && old_due != due
+    {
+        input["dueDate"] = json!(due);
+        changes.push(change("due", old_due, &due));
+    } else if args.no_due && issue.due_date.is_some() {
+        input["dueDate"] = Value::Null;
+        changes.push(change("due", old_due, ""));
+    }
+
+    Ok(Plan {
+        issue,
+        input,
+        changes,
+    })
+}
+
+fn change(key: &str, old: &str, new: &str) -> String {
+    let shown = |value: &str| {
+        if value.is_empty() {
+            "(none)".to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    format!("{key} {} → {}", shown(old).red(), shown(new).green())
+}
+
+/// Send the updates with at most `concurrency` requests in flight, reporting
+/// each result as it arrives.
+async fn apply(
+    client: &LinearClient,
+    plans: &[Plan<'_>],
+    concurrency: usize,
+    json_output: bool,
+) -> Result<()> {
+    let mut results: Vec<Value> = vec![Value::Null; plans.len()];
+    let mut failed = 0;
+    let mut pending = plans.iter().enumerate();
+    let mut tasks = JoinSet::new();
+    loop {
+        while tasks.len() < concurrency
+            && let Some((index, plan)) = pending.next()
+        {
+            let client = client.clone();
+            let id = plan.issue.id.clone();
+            let input = plan.input.clone();
+            tasks.spawn(async move { (index, update(&client, &id, input).await) });
+        }
+        let Some(joined) = tasks.join_next().await else {
+            break;
+        };
+        let (index, outcome) = joined?;
+        let issue = plans[index].issue;
+        let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+        results[index] = match outcome {
+            Ok(updated) => {
+                if !json_output {
+                    println!("Updated {identifier}");
+                }
+                json!({ "id": identifier, "success": true, "issue": updated })
+            }
+            Err(err) => {
+                failed += 1;
+                let error = format!("{err:#}");
+                if !json_output {
+                    eprintln!("{} {identifier}: {error}", "Failed".red().bold());
+                }
+                json!({ "id": identifier, "success": false, "error": error })
+            }
+        };
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&Value::Array(results))?);
+    }
+    if failed > 0 {
+        bail!("{failed} of {} issue(s) failed", plans.len());
+    }
+    Ok(())
+}
+
+async fn update(client: &LinearClient, id: &str, input: Value) -> Result<Issue> {
+    let resp: IssueUpdateResponse = client
+        .query(mutations::ISSUE_UPDATE, json!({ "id": id, "input": input }))
+        .await?;
+    match resp.issue_update {
+        MutationResult {
+            success: true,
+            issue: Some(issue),
+        } => Ok(issue),
+        _ => bail!("Update failed"),
+    }
+}
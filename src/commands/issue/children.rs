@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use crate::api::client::LinearClient;
+use crate::api::{paginate, resolve};
+use crate::config;
+use crate::output;
+
+pub async fn run(id: String, json_output: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let parent_id = resolve::issue_id(&client, &id).await?;
+    let mut children = paginate::issue_children(&client, &parent_id).await?;
+    // Drop the leaf probe; `issue tree` shows deeper levels.
+    for child in &mut children {
+        child.children = None;
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&children)?);
+    } else if children.is_empty() {
+        println!("{id} has no sub-issues.");
+    } else {
+        output::issue_table(&children);
+    }
+    Ok(())
+}
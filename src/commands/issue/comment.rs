@@ -1,31 +1,52 @@
 use anyhow::{Result, bail};
 use serde_json::json;
-use std::io::{IsTerminal, Read};
+use std::path::PathBuf;
 
 use crate::api::client::LinearClient;
-use crate::api::mutations;
-use crate::api::queries;
-use crate::api::types::{CommentCreateResponse, IssueResponse};
+use crate::api::types::CommentCreateResponse;
+use crate::api::{mutations, resolve};
+use crate::cli::TemplateArgs;
+use crate::commands::comment;
 use crate::config;
+use crate::template::{self, Template};
 
-pub async fn run(id: String, body: Option<String>, json_output: bool) -> Result<()> {
-    let body = match body {
-        Some(b) => b,
-        None => read_body_from_stdin()?,
+pub async fn run(
+    ids: Vec<String>,
+    body: Option<String>,
+    body_file: Option<PathBuf>,
+    json_output: bool,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let body_on_stdin = match &body_file {
+        Some(path) => path.as_os_str() == "-",
+        None => body.is_none(),
     };
-
-    if body.trim().is_empty() {
-        bail!("Comment body cannot be empty");
+    if body_on_stdin && super::reads_stdin(&ids) {
+        bail!("Pass --body or --body-file <FILE> when reading issue IDs from stdin");
     }
-
+    // Resolve the IDs first so a failure doesn't discard a body typed in $EDITOR.
+    let ids = super::read_ids(ids)?;
+    let body = comment::read_body(body, body_file.as_deref(), "")?;
     let client = LinearClient::new(config::api_key()?);
+    super::for_each_id(ids, |id| {
+        add_comment(&client, id, &body, json_output, template.as_ref())
+    })
+    .await
+}
 
+async fn add_comment(
+    client: &LinearClient,
+    id: String,
+    body: &str,
+    json_output: bool,
+    template: Option<&Template>,
+) -> Result<()> {
     // Resolve identifier to ID if needed
     let issue_id = if id.contains('-') {
-        let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
-        resp.issue.id
+        resolve::issue_id(client, &id).await?
     } else {
-        id
+        id.clone()
     };
 
     let resp: CommentCreateResponse = client
@@ -40,27 +61,22 @@ pub async fn run(id: String, body: Option<String>, json_output: bool) -> Result<
             if let Some(comment) = &resp.comment_create.comment {
                 println!("{}", serde_json::to_string_pretty(comment)?);
             }
+        } else if let Some(template) = template {
+            if let Some(comment) = &resp.comment_create.comment {
+                template::print_one(template, comment)?;
+            }
         } else if let Some(comment) = &resp.comment_create.comment {
             let author = comment
                 .user
                 .as_ref()
                 .and_then(|u| u.display_name.as_deref().or(u.name.as_deref()))
                 .unwrap_or("you");
-            println!("Comment added by {author}");
+            println!("Comment added to {id} by {author}");
         } else {
-            println!("Comment added.");
+            println!("Comment added to {id}.");
         }
     } else {
         bail!("Failed to create comment");
     }
     Ok(())
 }
-
-fn read_body_from_stdin() -> Result<String> {
-    if std::io::stdin().is_terminal() {
-        bail!("Provide comment body with -b/--body, or pipe via stdin");
-    }
-    let mut body = String::new();
-    std::io::stdin().read_to_string(&mut body)?;
-    Ok(body)
-}
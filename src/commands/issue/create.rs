@@ -1,12 +1,18 @@
-use anyhow::Result;
-use serde_json::json;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
 
-use crate::api::client::LinearClient;
+use crate::api::client::{self, LinearClient};
 use crate::api::types::*;
 use crate::api::{mutations, queries, resolve};
-use crate::cli::CreateArgs;
+use crate::cli::{CreateArgs, StateType};
 use crate::config;
+use crate::editor;
+use crate::frontmatter::{self, IssueFields};
 use crate::interactive;
+use crate::template::{self, Template};
 
 struct TeamOption {
     id: String,
@@ -31,7 +37,36 @@ impl std::fmt::Display for StateOption {
     }
 }
 
+/// Namespace for turning `--idempotency-key` values into issue IDs.
+const IDEMPOTENCY_NAMESPACE: Uuid = Uuid::from_u128(0x6c1f_0b7e_53a4_4d2e_9a51_c0de_11ea_7001);
+
+/// Fields of one issue to create, from flags or a Markdown file.
+#[derive(Default)]
+struct Draft {
+    id: Option<Uuid>,
+    team: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    priority: Option<i32>,
+    state: Option<String>,
+    state_type: Option<StateType>,
+    assignee: Option<String>,
+    labels: Vec<String>,
+    project: Option<String>,
+    milestone: Option<String>,
+    cycle: Option<String>,
+    parent: Option<String>,
+    estimate: Option<i64>,
+    due: Option<String>,
+    subscribers: Vec<String>,
+    sort_order: Option<f64>,
+}
+
 pub async fn run(args: CreateArgs) -> Result<()> {
+    let template = template::from_args(&args.template)?;
+    if let Some(path) = &args.from_file {
+        return run_files(&args, path, template.as_ref()).await;
+    }
     let client = LinearClient::new(config::api_key()?);
 
     // Resolve team
@@ -54,7 +89,7 @@ pub async fn run(args: CreateArgs) -> Result<()> {
     };
 
     // Get title
-    let title = interactive::require_text("title", "Issue title:", args.title)?;
+    let title = interactive::require_text("title", "Issue title:", args.title.clone())?;
 
     // Build mutation input
     let mut input = json!({
@@ -62,18 +97,18 @@ pub async fn run(args: CreateArgs) -> Result<()> {
         "title": title,
     });
 
-    if let Some(desc) = args.description {
-        input["description"] = json!(desc);
-    }
-
-    if let Some(p) = args.priority {
-        input["priority"] = json!(p);
-    }
+    let description = match (&args.description, &args.description_file) {
+        (Some(desc), _) => Some(desc.clone()),
+        (None, Some(path)) => Some(interactive::read_file(path)?),
+        (None, None) if interactive::is_tty() => Some(editor::compose(
+            "",
+            &format!("Description for \"{title}\" in Markdown."),
+            "description",
+        )?),
+        (None, None) => None,
+    };
 
-    if let Some(state_name) = &args.state {
-        let sid = resolve::state_id(&client, &team_id, state_name).await?;
-        input["stateId"] = json!(sid);
-    } else if interactive::is_tty() {
+    if args.state.is_none() && args.state_type.is_none() && interactive::is_tty() {
         let states: WorkflowStatesResponse = client
             .query(
                 queries::WORKFLOW_STATES,
@@ -95,33 +130,332 @@ pub async fn run(args: CreateArgs) -> Result<()> {
         }
     }
 
-    if let Some(assignee) = &args.assignee {
-        let uid = resolve::user_id(&client, assignee).await?;
+    let draft = Draft {
+        description,
+        ..draft_from_args(&args)
+    };
+    apply_draft(&client, &team_id, &draft, &mut input).await?;
+
+    let issue = send(&client, input).await?;
+    let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let title = issue.title.as_deref().unwrap_or("");
+    let mut message = format!("Created {id}: {title}");
+    if let Some(url) = issue.url.as_deref().filter(|u| !u.is_empty()) {
+        message.push_str(&format!("\n{url}"));
+    }
+    super::print_issue(&issue, args.json, template.as_ref(), &message)
+}
+
+/// Flags that also act as defaults for `--from-file` documents.
+fn draft_from_args(args: &CreateArgs) -> Draft {
+    let key_id = args
+        .idempotency_key
+        .as_ref()
+        .map(|key| Uuid::new_v5(&IDEMPOTENCY_NAMESPACE, key.as_bytes()));
+    Draft {
+        id: args.id.or(key_id),
+        team: args.team.clone(),
+        title: args.title.clone(),
+        priority: args.priority,
+        state: args.state.clone(),
+        state_type: args.state_type,
+        assignee: args.assignee.clone(),
+        labels: args.label.clone(),
+        project: args.project.clone(),
+        milestone: args.milestone.clone(),
+        cycle: args.cycle.clone(),
+        parent: args.parent.clone(),
+        estimate: args.estimate,
+        due: args.due.map(|d| d.to_string()),
+        subscribers: args.subscriber.clone(),
+        sort_order: args.sort_order,
+        ..Draft::default()
+    }
+}
+
+/// Resolve everything except team and title into `input`.
+async fn apply_draft(
+    client: &LinearClient,
+    team_id: &str,
+    draft: &Draft,
+    input: &mut Value,
+) -> Result<()> {
+    if let Some(id) = draft.id {
+        input["id"] = json!(id.to_string());
+    }
+
+    if let Some(desc) = draft.description.as_ref().filter(|d| !d.trim().is_empty()) {
+        input["description"] = json!(desc);
+    }
+
+    if let Some(p) = draft.priority {
+        input["priority"] = json!(p);
+    }
+
+    if let Some(state_name) = &draft.state {
+        let sid = resolve::state_id(client, team_id, state_name).await?;
+        input["stateId"] = json!(sid);
+    } else if let Some(state_type) = draft.state_type {
+        let state = super::state::state_of_type(client, team_id, state_type).await?;
+        input["stateId"] = json!(state.id);
+    }
+
+    if let Some(assignee) = &draft.assignee {
+        let uid = resolve::user_id(client, assignee).await?;
         input["assigneeId"] = json!(uid);
     }
 
-    if let Some(label) = &args.label {
-        let lid = resolve::label_id(&client, label).await?;
-        input["labelIds"] = json!([lid]);
+    if !draft.labels.is_empty() {
+        let mut label_ids = Vec::new();
+        for label in &draft.labels {
+            label_ids.push(resolve::label_id(client, label).await?);
+        }
+        input["labelIds"] = json!(label_ids);
+    }
+
+    let project_id = match &draft.project {
+        Some(project) => Some(resolve::project_id(client, project).await?),
+        None => None,
+    };
+    if let Some(milestone) = &draft.milestone {
+        let Some(project_id) = &project_id else {
+            bail!("--milestone needs a project");
+        };
+        input["projectMilestoneId"] =
+            json!(resolve::milestone_id(client, project_id, milestone).await?);
+    }
+    if let Some(project_id) = project_id {
+        input["projectId"] = json!(project_id);
+    }
+
+    if let Some(cycle) = &draft.cycle {
+        input["cycleId"] = json!(resolve::cycle_id(client, team_id, cycle).await?);
+    }
+
+    if let Some(parent) = &draft.parent {
+        input["parentId"] = json!(resolve::issue_id(client, parent).await?);
+    }
+
+    if let Some(estimate) = draft.estimate {
+        input["estimate"] = json!(estimate);
+    }
+
+    if let Some(due) = &draft.due {
+        input["dueDate"] = json!(due);
+    }
+
+    if !draft.subscribers.is_empty() {
+        let mut subscriber_ids = Vec::new();
+        for user in &draft.subscribers {
+            subscriber_ids.push(resolve::user_id(client, user).await?);
+        }
+        input["subscriberIds"] = json!(subscriber_ids);
+    }
+
+    if let Some(sort_order) = draft.sort_order {
+        input["sortOrder"] = json!(sort_order);
+    }
+    Ok(())
+}
+
+/// Create the issue. With a client-chosen `id`, a conflict or a lost connection
+/// is checked against an issue that already exists under that ID (e.g. a
+/// re-run after a timeout). Any other error is returned as is.
+async fn send(client: &LinearClient, input: Value) -> Result<Issue> {
+    let result: Result<IssueCreateResponse> = client
+        .query(mutations::ISSUE_CREATE, json!({ "input": &input }))
+        .await;
+    let resp = match (result, input["id"].as_str()) {
+        (Ok(resp), _) => resp,
+        (Err(err), Some(id))
+            if client::is_transport_error(&err) || client::is_conflict_error(&err) =>
+        {
+            // `ISSUE` selects the same fields as the create mutation.
+            let existing: Result<IssueResponse> =
+                client.query(queries::ISSUE, json!({ "id": id })).await;
+            let Ok(existing) = existing else {
+                return Err(err);
+            };
+            let identifier = existing.issue.identifier.as_deref().unwrap_or(id);
+            eprintln!("Issue {identifier} already exists with this ID; returning it.");
+            return Ok(existing.issue);
+        }
+        (Err(err), _) => return Err(err),
+    };
+    if !resp.issue_create.success {
+        bail!("Failed to create issue");
     }
+    resp.issue_create
+        .issue
+        .ok_or_else(|| anyhow::anyhow!("Issue was created but not returned"))
+}
 
-    let resp: IssueCreateResponse = client
-        .query(mutations::ISSUE_CREATE, json!({ "input": input }))
-        .await?;
+/// `--from-file`: create one issue per Markdown file (or per `*.md` in a directory).
+async fn run_files(args: &CreateArgs, path: &Path, template: Option<&Template>) -> Result<()> {
+    let files = markdown_files(path)?;
+    if files.is_empty() {
+        bail!("No .md files found in {}", path.display());
+    }
 
-    if resp.issue_create.success {
-        if let Some(issue) = resp.issue_create.issue {
-            let id = issue.identifier.as_deref().unwrap_or(&issue.id);
-            let title = issue.title.as_deref().unwrap_or("");
-            let url = issue.url.as_deref().unwrap_or("");
-            println!("Created {id}: {title}");
-            if !url.is_empty() {
-                println!("{url}");
+    // Parse everything first so a typo in one file does not leave a half-created batch.
+    let mut drafts = Vec::new();
+    for file in &files {
+        let mut draft = read_draft(file, args).with_context(|| format!("In {}", file.display()))?;
+        if let Some(key) = &args.idempotency_key {
+            let key = format!("{key}\0{}", file_key(path, file));
+            draft.id = Some(Uuid::new_v5(&IDEMPOTENCY_NAMESPACE, key.as_bytes()));
+        }
+        drafts.push(draft);
+    }
+
+    let client = LinearClient::new(config::api_key()?);
+    let mut created = Vec::new();
+    let mut failed = 0;
+    for (file, draft) in files.iter().zip(&drafts) {
+        let name = file.display();
+        match create_draft(&client, draft, args.dry_run).await {
+            Ok(None) => {
+                let team = draft.team.as_deref().unwrap_or_default();
+                let title = draft.title.as_deref().unwrap_or_default();
+                println!("Would create in {team}: {title} ({name})");
+            }
+            Ok(Some(issue)) if args.json => created.push(issue),
+            Ok(Some(issue)) => {
+                let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+                let title = issue.title.as_deref().unwrap_or("");
+                let message = format!("Created {id}: {title} ({name})");
+                super::print_issue(&issue, false, template, &message)?;
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("{} {name}: {err:#}", "Failed".red().bold());
             }
         }
-    } else {
-        anyhow::bail!("Failed to create issue");
     }
 
+    // One JSON array for the whole batch, including partial results on failure.
+    if args.json && !args.dry_run {
+        println!("{}", serde_json::to_string_pretty(&created)?);
+    }
+    if failed > 0 {
+        bail!("{failed} of {} file(s) failed", files.len());
+    }
     Ok(())
 }
+
+/// A name for `file` that does not depend on how `root` was typed or on the
+/// current directory: its path inside a `--from-file` directory, or its file
+/// name when `root` is the file itself.
+fn file_key(root: &Path, file: &Path) -> String {
+    let relative = file
+        .strip_prefix(root)
+        .ok()
+        .filter(|p| !p.as_os_str().is_empty())
+        .or_else(|| file.file_name().map(Path::new))
+        .unwrap_or(file);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn markdown_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    let entries =
+        std::fs::read_dir(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    for entry in entries {
+        let file = entry?.path();
+        if file.is_file() && file.extension().is_some_and(|ext| ext == "md") {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Front matter fields override the command-line flags, which act as defaults.
+fn read_draft(file: &Path, args: &CreateArgs) -> Result<Draft> {
+    let text = interactive::read_file(file)?;
+    let (fields, body) = IssueFields::parse(&text)?;
+    let set = |value: Option<String>| value.filter(|v| !v.is_empty());
+    let defaults = draft_from_args(args);
+
+    // The leading `# Heading` is only taken out of the body when it becomes the title.
+    let (title, body) = match set(fields.title) {
+        Some(title) => (Some(title), body),
+        None => match split_heading(&body) {
+            (Some(heading), rest) => (Some(heading), rest),
+            (None, _) => (defaults.title, body),
+        },
+    };
+    if title.is_none() {
+        bail!("Missing title: set `title` in the front matter or start the body with `# Title`");
+    }
+    let team = set(fields.team).or(defaults.team);
+    if team.is_none() {
+        bail!("Missing team: set `team` in the front matter or pass --team");
+    }
+
+    Ok(Draft {
+        team,
+        title,
+        description: Some(body),
+        priority: match set(fields.priority) {
+            Some(priority) => Some(frontmatter::parse_priority(&priority)?),
+            None => defaults.priority,
+        },
+        // A state named in the file overrides --state-type.
+        state_type: defaults
+            .state_type
+            .filter(|_| fields.state.as_deref().is_none_or(str::is_empty)),
+        state: set(fields.state).or(defaults.state),
+        assignee: set(fields.assignee).or(defaults.assignee),
+        labels: fields.labels.unwrap_or(defaults.labels),
+        project: set(fields.project).or(defaults.project),
+        parent: set(fields.parent).or(defaults.parent),
+        estimate: set(fields.estimate)
+            .map(|e| {
+                e.parse::<i64>()
+                    .map_err(|_| anyhow::anyhow!("Invalid estimate '{e}'. Use a whole number"))
+            })
+            .transpose()?
+            .or(defaults.estimate),
+        due: set(fields.due).or(defaults.due),
+        ..defaults
+    })
+}
+
+/// Use a leading `# Heading` as the title and drop it from the description.
+fn split_heading(body: &str) -> (Option<String>, String) {
+    let trimmed = body.trim_start();
+    let (first, rest) = trimmed.split_once('\n').unwrap_or((trimmed, ""));
+    match first.strip_prefix("# ") {
+        Some(heading) if !heading.trim().is_empty() => {
+            (Some(heading.trim().to_string()), rest.trim().to_string())
+        }
+        _ => (None, body.to_string()),
+    }
+}
+
+/// Create one draft, or only resolve it when `dry_run` is set.
+async fn create_draft(
+    client: &LinearClient,
+    draft: &Draft,
+    dry_run: bool,
+) -> Result<Option<Issue>> {
+    let team = draft.team.as_deref().unwrap_or_default();
+    let team_id = resolve::team_id(client, team).await?;
+    let mut input = json!({
+        "teamId": team_id,
+        "title": draft.title,
+    });
+    apply_draft(client, &team_id, draft, &mut input).await?;
+    if dry_run {
+        return Ok(None);
+    }
+    send(client, input).await.map(Some)
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::queries;
+use crate::api::types::IssueResponse;
+use crate::cli::TemplateArgs;
+use crate::config;
+use crate::template;
+
+/// Show the issue named by the current git branch.
+pub async fn run(json: bool, template_args: TemplateArgs) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let id = super::current_id()?;
+    let client = LinearClient::new(config::api_key()?);
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let title = issue.title.as_deref().unwrap_or("");
+    let mut message = format!("{identifier}: {title}");
+    if let Some(url) = issue.url.as_deref().filter(|u| !u.is_empty()) {
+        message.push_str(&format!("\n{url}"));
+    }
+    super::print_issue(&issue, json, template.as_ref(), &message)
+}
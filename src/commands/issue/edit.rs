@@ -0,0 +1,276 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::{Value, json};
+
+use crate::api::client::LinearClient;
+use crate::api::types::{Issue, IssueResponse};
+use crate::api::{queries, resolve};
+use crate::cli::TemplateArgs;
+use crate::commands::issue::update;
+use crate::config;
+use crate::editor;
+use crate::frontmatter::{self, IssueFields};
+use crate::interactive;
+use crate::template;
+
+enum Change {
+    Field {
+        key: &'static str,
+        old: String,
+        new: String,
+    },
+    Labels {
+        added: Vec<String>,
+        removed: Vec<String>,
+        labels: Vec<String>,
+    },
+    Description {
+        old: String,
+        new: String,
+    },
+}
+
+pub async fn run(id: String, yes: bool, json: bool, template_args: TemplateArgs) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    if !interactive::is_tty() {
+        bail!("`issue edit` needs an interactive terminal. Use `issue update` in scripts.");
+    }
+    let client = LinearClient::new(config::api_key()?);
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id).to_string();
+
+    let original = IssueFields::from_issue(&issue);
+    let description = issue.description.clone().unwrap_or_default();
+    let mut buffer = original.render(&description);
+    let (edited, body) = loop {
+        let text = editor::edit(&buffer)?;
+        match IssueFields::parse(&text).and_then(reject_create_only) {
+            Ok(parsed) => break parsed,
+            Err(err) => {
+                eprintln!("{} {err:#}", "error:".red().bold());
+                if !interactive::confirm("Re-open the editor?", false)? {
+                    bail!("Aborted; no changes were sent");
+                }
+                buffer = text;
+            }
+        }
+    };
+
+    let changes = diff(&original, &edited, &description, &body);
+    if changes.is_empty() {
+        println!("No changes.");
+        return Ok(());
+    }
+    print_changes(&identifier, &changes);
+
+    let prompt = format!("Apply {} change(s) to {identifier}?", changes.len());
+    if !interactive::confirm(&prompt, yes)? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let input = build_input(&client, &issue, &changes).await?;
+    update::send(&client, &issue.id, input, json, template.as_ref()).await
+}
+
+/// `team` and `parent` are only understood by `issue create --from-file`.
+fn reject_create_only(parsed: (IssueFields, String)) -> Result<(IssueFields, String)> {
+    let fields = &parsed.0;
+    if fields.team.is_some() || fields.parent.is_some() {
+        bail!("`team` and `parent` cannot be changed with `issue edit`");
+    }
+    Ok(parsed)
+}
+
+fn diff(
+    original: &IssueFields,
+    edited: &IssueFields,
+    old_body: &str,
+    new_body: &str,
+) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let fields = [
+        ("title", &original.title, &edited.title),
+        ("state", &original.state, &edited.state),
+        ("priority", &original.priority, &edited.priority),
+        ("assignee", &original.assignee, &edited.assignee),
+        ("project", &original.project, &edited.project),
+        ("estimate", &original.estimate, &edited.estimate),
+        ("due", &original.due, &edited.due),
+    ];
+    for (key, old, new) in fields {
+        let old = old.clone().unwrap_or_default();
+        if let Some(new) = new
+            && *new != old
+        {
+            changes.push(Change::Field {
+                key,
+                old,
+                new: new.clone(),
+            });
+        }
+    }
+
+    if let Some(labels) = &edited.labels {
+        let old = original.labels.clone().unwrap_or_default();
+        let added: Vec<String> = labels
+            .iter()
+            .filter(|l| !old.contains(l))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = old
+            .iter()
+            .filter(|l| !labels.contains(l))
+            .cloned()
+            .collect();
+        if !added.is_empty() || !removed.is_empty() {
+            changes.push(Change::Labels {
+                added,
+                removed,
+                labels: labels.clone(),
+            });
+        }
+    }
+
+    let old_body = frontmatter::body_text(old_body);
+    if old_body != new_body {
+        changes.push(Change::Description {
+            old: old_body.to_string(),
+            new: new_body.to_string(),
+        });
+    }
+    changes
+}
+
+fn print_changes(identifier: &str, changes: &[Change]) {
+    let shown = |value: &str| {
+        if value.is_empty() {
+            "(none)".dimmed().to_string()
+        } else {
+            value.to_string()
+        }
+    };
+    println!("{}", format!("Changes to {identifier}:").bold());
+    for change in changes {
+        match change {
+            Change::Field { key, old, new } => {
+                println!("  {:<12}{} → {}", key, shown(old).red(), shown(new).green());
+            }
+            Change::Labels { added, removed, .. } => {
+                let mut parts: Vec<String> = added
+                    .iter()
+                    .map(|l| format!("+{l}").green().to_string())
+                    .collect();
+                parts.extend(removed.iter().map(|l| format!("-{l}").red().to_string()));
+                println!("  {:<12}{}", "labels", parts.join(" "));
+            }
+            Change::Description { old, new } => {
+                println!("  description");
+                for line in line_diff(old, new) {
+                    println!("    {line}");
+                }
+            }
+        }
+    }
+    println!();
+}
+
+/// Changed lines only, `-` for removed and `+` for added, in document order.
+fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push(format!("+ {}", b[j]).green().to_string());
+            j += 1;
+        } else {
+            out.push(format!("- {}", a[i]).red().to_string());
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Resolve names the same way `issue update` does and build an `IssueUpdateInput`.
+async fn build_input(client: &LinearClient, issue: &Issue, changes: &[Change]) -> Result<Value> {
+    let mut input = json!({});
+    for change in changes {
+        match change {
+            Change::Field { key, new, .. } => {
+                let new = new.as_str();
+                match *key {
+                    "title" if new.is_empty() => bail!("Title cannot be empty"),
+                    "title" => input["title"] = json!(new),
+                    "state" => {
+                        let team_id = issue
+                            .team
+                            .as_ref()
+                            .map(|t| t.id.as_str())
+                            .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+                        if new.is_empty() {
+                            bail!("State cannot be empty");
+                        }
+                        input["stateId"] = json!(resolve::state_id(client, team_id, new).await?);
+                    }
+                    "priority" => input["priority"] = json!(frontmatter::parse_priority(new)?),
+                    "assignee" if new.is_empty() => input["assigneeId"] = Value::Null,
+                    "assignee" => input["assigneeId"] = json!(resolve::user_id(client, new).await?),
+                    "project" if new.is_empty() => input["projectId"] = Value::Null,
+                    "project" => {
+                        input["projectId"] = json!(resolve::project_id(client, new).await?);
+                    }
+                    "estimate" if new.is_empty() => input["estimate"] = Value::Null,
+                    "estimate" => {
+                        let estimate: i64 = new.parse().map_err(|_| {
+                            anyhow::anyhow!("Invalid estimate '{new}'. Use a whole number")
+                        })?;
+                        input["estimate"] = json!(estimate);
+                    }
+                    "due" if new.is_empty() => input["dueDate"] = Value::Null,
+                    "due" => input["dueDate"] = json!(frontmatter::parse_due(new)?.to_string()),
+                    _ => unreachable!("unknown front matter field {key}"),
+                }
+            }
+            Change::Labels { labels, .. } => {
+                let current = issue
+                    .labels
+                    .as_ref()
+                    .map(|c| c.nodes.as_slice())
+                    .unwrap_or_default();
+                let mut label_ids = Vec::new();
+                for name in labels {
+                    let existing = current
+                        .iter()
+                        .find(|l| l.name.as_deref() == Some(name.as_str()));
+                    let id = match existing {
+                        Some(label) => label.id.clone(),
+                        None => resolve::label_id(client, name).await?,
+                    };
+                    if !label_ids.contains(&id) {
+                        label_ids.push(id);
+                    }
+                }
+                input["labelIds"] = json!(label_ids);
+            }
+            Change::Description { new, .. } => input["description"] = json!(new),
+        }
+    }
+    Ok(input)
+}
@@ -0,0 +1,19 @@
+use anyhow::Result;
+
+use crate::api::client::LinearClient;
+use crate::api::{paginate, resolve};
+use crate::config;
+use crate::output;
+
+pub async fn run(id: String, json_output: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let issue_id = resolve::issue_id(&client, &id).await?;
+    let entries = paginate::issue_history(&client, &issue_id).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+    } else {
+        output::history_timeline(&entries);
+    }
+    Ok(())
+}
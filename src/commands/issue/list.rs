@@ -1,17 +1,43 @@
 use anyhow::Result;
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::api::client::LinearClient;
 use crate::api::queries;
 use crate::api::resolve;
 use crate::api::types::IssuesResponse;
-use crate::cli::ListArgs;
+use crate::cli::{IssueFilterArgs, ListArgs, ListFormat};
 use crate::config;
 use crate::output;
+use crate::template;
 
 pub async fn run(args: ListArgs) -> Result<()> {
+    let template = template::from_args(&args.template)?;
     let client = LinearClient::new(config::api_key()?);
+    let filter = build_filter(&client, &args.filter).await?;
 
+    let variables = json!({
+        "filter": filter,
+        "first": args.limit,
+        "includeArchived": args.filter.archived,
+    });
+
+    let resp: IssuesResponse = client.query(queries::ISSUES, variables).await?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&resp.issues.nodes)?);
+    } else if args.format == ListFormat::Ids {
+        for issue in &resp.issues.nodes {
+            println!("{}", issue.identifier.as_deref().unwrap_or(&issue.id));
+        }
+    } else if let Some(template) = &template {
+        template::print_each(template, &resp.issues.nodes)?;
+    } else {
+        output::issue_table(&resp.issues.nodes);
+    }
+    Ok(())
+}
+
+/// Translate list filters into an `IssueFilter`, resolving the assignee.
+pub async fn build_filter(client: &LinearClient, args: &IssueFilterArgs) -> Result<Value> {
     let mut filter = json!({});
 
     if let Some(team) = &args.team {
@@ -20,7 +46,7 @@ pub async fn run(args: ListArgs) -> Result<()> {
 
     if let Some(state) = &args.state {
         filter["state"] = json!({ "name": { "eqIgnoreCase": state } });
-    } else if !args.all {
+    } else if !args.all && !args.archived {
         filter["state"] = json!({ "type": { "nin": ["completed", "canceled"] } });
     }
 
@@ -36,7 +62,7 @@ pub async fn run(args: ListArgs) -> Result<()> {
     };
 
     if let Some(assignee) = resolved_assignee {
-        let assignee_id = resolve::user_id(&client, assignee).await?;
+        let assignee_id = resolve::user_id(client, assignee).await?;
         filter["assignee"] = json!({ "id": { "eq": assignee_id } });
     }
 
@@ -52,16 +78,8 @@ pub async fn run(args: ListArgs) -> Result<()> {
         filter["project"] = json!({ "name": { "containsIgnoreCase": project } });
     }
 
-    let variables = json!({
-        "filter": filter,
-        "first": args.limit,
-    });
-
-    let resp: IssuesResponse = client.query(queries::ISSUES, variables).await?;
-    if args.json {
-        println!("{}", serde_json::to_string_pretty(&resp.issues.nodes)?);
-    } else {
-        output::issue_table(&resp.issues.nodes);
+    if args.archived {
+        filter["archivedAt"] = json!({ "null": false });
     }
-    Ok(())
+    Ok(filter)
 }
@@ -1,8 +1,218 @@
+pub mod archive;
 pub mod assign;
+pub mod bulk_update;
+pub mod children;
 pub mod comment;
 pub mod create;
+pub mod current;
+pub mod edit;
+pub mod history;
 pub mod list;
+pub mod relate;
+pub mod relations;
+pub mod reparent;
 pub mod search;
+pub mod start;
 pub mod state;
+pub mod subscribe;
+pub mod transfer;
+pub mod tree;
+pub mod unrelate;
 pub mod update;
 pub mod view;
+
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::types::Issue;
+use crate::cli;
+use crate::git;
+use crate::interactive;
+use crate::template::{self, Template};
+
+/// Print an issue returned by a mutation as JSON, through a template, or as `message`.
+pub fn print_issue(
+    issue: &Issue,
+    json: bool,
+    template: Option<&Template>,
+    message: &str,
+) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(issue)?);
+    } else if let Some(template) = template {
+        template::print_one(template, issue)?;
+    } else {
+        println!("{message}");
+    }
+    Ok(())
+}
+
+/// Whether any of the ID arguments is `-`, i.e. stdin is taken by the IDs.
+pub fn reads_stdin(ids: &[String]) -> bool {
+    ids.iter().any(|id| id == "-")
+}
+
+/// Expand `-` into identifiers read from stdin: the first word of each line
+/// (as printed by `issue list --format ids` or a template), or JSON strings,
+/// objects with `identifier`/`id`, and arrays of them (as printed by `--json`).
+/// Without arguments, the issue named by the current git branch is used.
+pub fn read_ids(args: Vec<String>) -> Result<Vec<String>> {
+    if args.is_empty() {
+        let (id, branch) = branch_issue()?;
+        // The branch name is only a guess, so say which issue is being used.
+        eprintln!(
+            "{} using {id} from branch '{branch}'",
+            "note:".yellow().bold()
+        );
+        return Ok(vec![id]);
+    }
+    let mut ids = Vec::new();
+    let mut read_stdin = false;
+    for arg in args {
+        if arg != "-" {
+            ids.push(arg);
+        } else if !read_stdin {
+            read_stdin = true;
+            let text = interactive::read_file(Path::new("-"))?;
+            ids.extend(parse_ids(&text)?);
+        }
+    }
+    if ids.is_empty() {
+        bail!("No issue IDs given");
+    }
+    Ok(ids)
+}
+
+/// The issue named by the current git branch, for commands run without an ID.
+pub fn current_id() -> Result<String> {
+    branch_issue().map(|(id, _)| id)
+}
+
+/// The issue ID found in the current branch, and the branch.
+fn branch_issue() -> Result<(String, String)> {
+    let Some(branch) = git::current_branch()? else {
+        bail!("No issue ID given, and not on a git branch to take one from");
+    };
+    match issue_id_in_branch(&branch) {
+        Some(id) => Ok((id, branch)),
+        None => bail!("No issue ID given, and none found in branch '{branch}'"),
+    }
+}
+
+/// The `TEAM-123` in a branch name. One opening a `/` segment, as in Linear's
+/// `alice/eng-123-fix-login`, wins over one later in a segment (`feature/fix-ENG-123`).
+fn issue_id_in_branch(branch: &str) -> Option<String> {
+    branch.split('/').find_map(id_at).or_else(|| {
+        branch.split('/').find_map(|segment| {
+            segment
+                .match_indices(['-', '_'])
+                .find_map(|(i, _)| id_at(&segment[i + 1..]))
+        })
+    })
+}
+
+/// `TEAM-123` at the start of `text` when followed by the end, `-` or `_`, so
+/// versions like `lodash-4.17.21` are not mistaken for issues.
+fn id_at(text: &str) -> Option<String> {
+    let (key, rest) = text.split_once('-')?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (number, after) = rest.split_at(digits);
+    let id = format!("{key}-{number}");
+    (key.starts_with(|c: char| c.is_ascii_alphabetic())
+        && cli::looks_like_issue_id(&id)
+        && (after.is_empty() || after.starts_with(['-', '_'])))
+    .then(|| id.to_uppercase())
+}
+
+fn parse_ids(text: &str) -> Result<Vec<String>> {
+    let text = text.trim();
+    if !text.starts_with(['[', '{', '"']) {
+        return Ok(text
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_string)
+            .collect());
+    }
+    let mut ids = Vec::new();
+    for value in serde_json::Deserializer::from_str(text).into_iter::<Value>() {
+        collect_ids(&value?, &mut ids)?;
+    }
+    Ok(ids)
+}
+
+fn collect_ids(value: &Value, ids: &mut Vec<String>) -> Result<()> {
+    match value {
+        Value::String(id) => ids.push(id.clone()),
+        Value::Array(items) => {
+            for item in items {
+                collect_ids(item, ids)?;
+            }
+        }
+        Value::Object(object) => {
+            let id = object
+                .get("identifier")
+                .or_else(|| object.get("id"))
+                .and_then(Value::as_str);
+            match id {
+                Some(id) => ids.push(id.to_string()),
+                None => bail!("Expected an `identifier` or `id` field in {value}"),
+            }
+        }
+        other => bail!("Expected issue identifiers on stdin, got {other}"),
+    }
+    Ok(())
+}
+
+/// Run `f` for each issue, continuing past failures and reporting them per
+/// issue. A single issue fails with its own error.
+pub async fn for_each_id<F, Fut>(ids: Vec<String>, mut f: F) -> Result<()>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    if let [id] = ids.as_slice() {
+        return f(id.clone()).await;
+    }
+    let total = ids.len();
+    let mut failed = 0;
+    for id in ids {
+        if let Err(err) = f(id.clone()).await {
+            failed += 1;
+            eprintln!("{} {id}: {err:#}", "Failed".red().bold());
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {total} issue(s) failed");
+    }
+    Ok(())
+}
+
+/// Split `ID... VALUE` arguments: with more than one argument the last is the
+/// value. A lone argument that is not an issue reference is the value too, so
+/// the ID can come from the current git branch. A last argument that looks
+/// like an issue ID is refused rather than taken as the `what` value.
+pub fn split_value(mut args: Vec<String>, what: &str) -> Result<(Vec<String>, Option<String>)> {
+    if let [.., _, last] = args.as_slice() {
+        if is_issue_ref(last) {
+            bail!("Expected a {what} after the issue IDs, got issue ID '{last}'");
+        }
+    }
+    let lone_value = matches!(args.as_slice(), [arg] if !is_issue_ref(arg));
+    let value = if args.len() > 1 || lone_value {
+        args.pop()
+    } else {
+        None
+    };
+    Ok((args, value))
+}
+
+/// Whether `arg` names an issue: an identifier, a UUID or `-` for stdin.
+fn is_issue_ref(arg: &str) -> bool {
+    arg == "-" || cli::looks_like_issue_id(arg) || Uuid::parse_str(arg).is_ok()
+}
@@ -0,0 +1,161 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, queries, resolve};
+use crate::cli::RelationKind;
+use crate::config;
+use crate::interactive;
+
+/// Linear's relation type, and whether the two issues must be swapped to express it.
+pub fn api_type(kind: RelationKind) -> (&'static str, bool) {
+    match kind {
+        RelationKind::Blocks => ("blocks", false),
+        RelationKind::BlockedBy => ("blocks", true),
+        RelationKind::DuplicateOf => ("duplicate", false),
+        RelationKind::DuplicatedBy => ("duplicate", true),
+        RelationKind::Related => ("related", false),
+        RelationKind::Similar => ("similar", false),
+    }
+}
+
+fn describe(kind: RelationKind) -> &'static str {
+    match kind {
+        RelationKind::Blocks => "blocks",
+        RelationKind::BlockedBy => "is blocked by",
+        RelationKind::DuplicateOf => "is a duplicate of",
+        RelationKind::DuplicatedBy => "is duplicated by",
+        RelationKind::Related => "is related to",
+        RelationKind::Similar => "is similar to",
+    }
+}
+
+pub async fn run(
+    id: String,
+    relation: Option<RelationKind>,
+    other: Option<String>,
+    duplicate_of: Option<String>,
+    cancel: bool,
+    json_output: bool,
+) -> Result<()> {
+    let (kind, other) = match (duplicate_of, relation, other) {
+        (Some(other), _, _) => (RelationKind::DuplicateOf, other),
+        (None, Some(kind), Some(other)) => (kind, other),
+        _ => bail!(
+            "Specify a relation and the other issue, e.g. `linear issue relate ENG-1 blocks ENG-2`"
+        ),
+    };
+    if id.eq_ignore_ascii_case(&other) {
+        bail!("An issue cannot be related to itself");
+    }
+
+    let client = LinearClient::new(config::api_key()?);
+    let (relation_type, swap) = api_type(kind);
+    let (from, to) = if swap { (&other, &id) } else { (&id, &other) };
+    let issue_id = resolve::issue_id(&client, from).await?;
+    let related_issue_id = resolve::issue_id(&client, to).await?;
+
+    let resp: IssueRelationCreateResponse = client
+        .query(
+            mutations::ISSUE_RELATION_CREATE,
+            json!({
+                "input": {
+                    "issueId": issue_id,
+                    "relatedIssueId": related_issue_id,
+                    "type": relation_type,
+                }
+            }),
+        )
+        .await?;
+    if !resp.issue_relation_create.success {
+        bail!("Failed to create relation");
+    }
+
+    if json_output {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&resp.issue_relation_create.issue_relation)?
+        );
+    } else {
+        println!("{id} {} {other}", describe(kind));
+    }
+
+    if kind == RelationKind::DuplicateOf {
+        offer_cancel(&client, &id, cancel, json_output).await?;
+    }
+    Ok(())
+}
+
+/// Move a duplicate to its team's canceled state: always with `--cancel`,
+/// otherwise only after confirming in a terminal.
+async fn offer_cancel(client: &LinearClient, id: &str, cancel: bool, quiet: bool) -> Result<()> {
+    if !cancel && (!interactive::is_tty() || quiet) {
+        return Ok(());
+    }
+
+    let resp: IssueResponse = client
+        .query(queries::ISSUE_REF, json!({ "id": id }))
+        .await?;
+    let issue = resp.issue;
+    if issue
+        .state
+        .as_ref()
+        .and_then(|s| s.state_type.as_deref())
+        .is_some_and(|t| t == "canceled")
+    {
+        return Ok(());
+    }
+    let team_id = issue
+        .team
+        .as_ref()
+        .map(|t| t.id.clone())
+        .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+
+    let states: WorkflowStatesResponse = client
+        .query(
+            queries::WORKFLOW_STATES,
+            json!({ "filter": { "team": { "id": { "eq": team_id } } } }),
+        )
+        .await?;
+    let canceled: Vec<WorkflowState> = states
+        .workflow_states
+        .nodes
+        .into_iter()
+        .filter(|s| s.state_type.as_deref() == Some("canceled"))
+        .collect();
+    // Prefer a dedicated "Duplicate" state when the team has one.
+    let Some(state) = canceled
+        .iter()
+        .find(|s| {
+            s.name
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case("duplicate"))
+        })
+        .or_else(|| canceled.first())
+    else {
+        if cancel {
+            bail!("The issue's team has no canceled state");
+        }
+        return Ok(());
+    };
+    let state_name = state.name.as_deref().unwrap_or("Canceled");
+
+    if !cancel && !interactive::confirm(&format!("Move {id} to {state_name}?"), false)? {
+        return Ok(());
+    }
+
+    let resp: IssueUpdateResponse = client
+        .query(
+            mutations::ISSUE_UPDATE,
+            json!({ "id": issue.id, "input": { "stateId": state.id } }),
+        )
+        .await?;
+    if !resp.issue_update.success {
+        bail!("Failed to update issue state");
+    }
+    if !quiet {
+        println!("{id}: → {state_name}");
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anyhow::Result;
+use serde_json::{Value, json};
+
+use crate::api::client::LinearClient;
+use crate::api::queries;
+use crate::api::types::IssueResponse;
+use crate::config;
+use crate::output;
+
+pub async fn run(id: String, json_output: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+
+    if !json_output {
+        output::relation_list(&issue);
+        return Ok(());
+    }
+
+    let outbound = issue.relations.iter().flat_map(|c| &c.nodes).map(|r| {
+        json!({
+            "id": r.id,
+            "type": r.relation_type,
+            "direction": "outbound",
+            "issue": r.related_issue,
+        })
+    });
+    let inbound = issue
+        .inverse_relations
+        .iter()
+        .flat_map(|c| &c.nodes)
+        .map(|r| {
+            json!({
+                "id": r.id,
+                "type": r.relation_type,
+                "direction": "inbound",
+                "issue": r.issue,
+            })
+        });
+    let relations: Vec<Value> = outbound.chain(inbound).collect();
+    println!("{}", serde_json::to_string_pretty(&relations)?);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::{Issue, IssueUpdateResponse};
+use crate::api::{mutations, resolve};
+use crate::config;
+
+/// Set the parent of each issue to `parent`, or clear it with `no_parent`.
+pub async fn run(
+    ids: Vec<String>,
+    parent: Option<String>,
+    no_parent: bool,
+    json_output: bool,
+) -> Result<()> {
+    if parent.is_none() && !no_parent {
+        bail!("Pass --parent <ISSUE> or --no-parent");
+    }
+    let client = LinearClient::new(config::api_key()?);
+    let parent_id = match &parent {
+        Some(parent) => Some(resolve::issue_id(&client, parent).await?),
+        None => None,
+    };
+
+    let ids = super::read_ids(ids)?;
+    let updated = RefCell::new(Vec::new());
+    let result = super::for_each_id(ids, |id| {
+        reparent(
+            &client,
+            id,
+            parent.as_deref(),
+            parent_id.as_deref(),
+            json_output,
+            &updated,
+        )
+    })
+    .await;
+
+    // Partial results are still printed when some issues failed.
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&updated.into_inner())?);
+    }
+    result
+}
+
+async fn reparent(
+    client: &LinearClient,
+    id: String,
+    parent: Option<&str>,
+    parent_id: Option<&str>,
+    json_output: bool,
+    updated: &RefCell<Vec<Issue>>,
+) -> Result<()> {
+    let issue_id = resolve::issue_id(client, &id).await?;
+    if parent_id == Some(issue_id.as_str()) {
+        bail!("{id} cannot be its own parent");
+    }
+    let resp: IssueUpdateResponse = client
+        .query(
+            mutations::ISSUE_UPDATE,
+            json!({ "id": issue_id, "input": { "parentId": parent_id } }),
+        )
+        .await?;
+    if !resp.issue_update.success {
+        bail!("Failed to update {id}");
+    }
+    let Some(issue) = resp.issue_update.issue else {
+        return Ok(());
+    };
+    if !json_output {
+        let identifier = issue.identifier.as_deref().unwrap_or(&id);
+        match parent {
+            Some(parent) => println!("Moved {identifier} under {parent}"),
+            None => println!("Detached {identifier} from its parent"),
+        }
+    }
+    updated.borrow_mut().push(issue);
+    Ok(())
+}
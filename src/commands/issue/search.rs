@@ -4,22 +4,24 @@ use serde_json::json;
 use crate::api::client::LinearClient;
 use crate::api::queries;
 use crate::api::types::IssueSearchResponse;
+use crate::cli::TemplateArgs;
 use crate::config;
 use crate::output;
+use crate::template;
 
-pub async fn run(query: String, json: bool) -> Result<()> {
+pub async fn run(query: String, json: bool, template_args: TemplateArgs) -> Result<()> {
+    let template = template::from_args(&template_args)?;
     let client = LinearClient::new(config::api_key()?);
     let resp: IssueSearchResponse = client
-        .query(
-            queries::ISSUE_SEARCH,
-            json!({ "term": query, "first": 50 }),
-        )
+        .query(queries::ISSUE_SEARCH, json!({ "term": query, "first": 50 }))
         .await?;
     if json {
         println!(
             "{}",
             serde_json::to_string_pretty(&resp.search_issues.nodes)?
         );
+    } else if let Some(template) = &template {
+        template::print_each(template, &resp.search_issues.nodes)?;
     } else {
         output::issue_table(&resp.search_issues.nodes);
     }
@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, queries, resolve};
+use crate::cli::{StartArgs, StateType};
+use crate::config;
+use crate::git::{self, Checkout};
+use crate::template::{self, Template};
+
+/// Shared by every issue being started.
+struct Context<'a> {
+    client: LinearClient,
+    viewer_id: String,
+    /// First started state per team.
+    started: RefCell<HashMap<String, String>>,
+    json: bool,
+    template: Option<&'a Template>,
+}
+
+/// Assign issues to the viewer and move them to their team's first started
+/// state. With a single issue, also check out its suggested branch.
+pub async fn run(args: StartArgs) -> Result<()> {
+    let template = template::from_args(&args.template)?;
+    let ids = super::read_ids(args.ids)?;
+    let checkout = ids.len() == 1 && !args.no_checkout;
+    // Check what the checkout needs before changing any issue.
+    if checkout {
+        git::require_work_tree()?;
+    }
+    let client = LinearClient::new(config::api_key()?);
+    let viewer_id = resolve::user_id(&client, "me").await?;
+    let ctx = Context {
+        client,
+        viewer_id,
+        started: RefCell::new(HashMap::new()),
+        json: args.json,
+        template: template.as_ref(),
+    };
+
+    if checkout {
+        let issue = start(&ctx, &ids[0], true).await?;
+        let branch = issue.branch_name.as_deref().unwrap_or_default();
+        match git::checkout(branch)? {
+            Checkout::Existing => eprintln!("Switched to branch '{branch}'"),
+            Checkout::Tracking(remote) => {
+                eprintln!("Switched to a new branch '{branch}' tracking '{remote}'")
+            }
+            Checkout::Created => eprintln!("Switched to a new branch '{branch}'"),
+        }
+        return Ok(());
+    }
+    super::for_each_id(ids, |id| start_one(&ctx, id, args.no_checkout)).await
+}
+
+async fn start_one(ctx: &Context<'_>, id: String, no_checkout: bool) -> Result<()> {
+    let issue = start(ctx, &id, false).await?;
+    if no_checkout {
+        print_branch(ctx, &issue);
+    }
+    Ok(())
+}
+
+/// Assign and move one issue. `needs_branch` refuses, before any change, an
+/// issue Linear has no branch name for.
+async fn start(ctx: &Context<'_>, id: &str, needs_branch: bool) -> Result<Issue> {
+    let client = &ctx.client;
+    let resp: IssueResponse = client
+        .query(queries::ISSUE_REF, json!({ "id": id }))
+        .await?;
+    let issue = resp.issue;
+    if needs_branch && issue.branch_name.as_deref().is_none_or(str::is_empty) {
+        bail!("Linear has no branch name for {id}");
+    }
+    let team_id = issue
+        .team
+        .as_ref()
+        .map(|t| t.id.clone())
+        .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+
+    let mut input = json!({ "assigneeId": ctx.viewer_id });
+    // Leave issues already in progress (e.g. "In Review") where they are.
+    let started = StateType::Started.as_str();
+    let current_type = issue.state.as_ref().and_then(|s| s.state_type.as_deref());
+    if current_type != Some(started) {
+        let cached = ctx.started.borrow().get(&team_id).cloned();
+        let state_id = match cached {
+            Some(state_id) => state_id,
+            None => {
+                let state = resolve::states_of_type(client, &team_id, started)
+                    .await?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("Team has no started state"))?;
+                ctx.started.borrow_mut().insert(team_id, state.id.clone());
+                state.id
+            }
+        };
+        input["stateId"] = json!(state_id);
+    }
+
+    let resp: IssueUpdateResponse = client
+        .query(
+            mutations::ISSUE_UPDATE,
+            json!({ "id": issue.id, "input": input }),
+        )
+        .await?;
+    let issue = match resp.issue_update {
+        MutationResult {
+            success: true,
+            issue: Some(issue),
+        } => issue,
+        _ => bail!("Failed to start {id}"),
+    };
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let state = issue
+        .state
+        .as_ref()
+        .and_then(|s| s.name.as_deref())
+        .unwrap_or("?");
+    let message = format!("{identifier}: → {state}, assigned to you");
+    super::print_issue(&issue, ctx.json, ctx.template, &message)?;
+    Ok(issue)
+}
+
+/// `--no-checkout`: the branch name on stdout, or stderr when stdout is JSON or a template.
+fn print_branch(ctx: &Context<'_>, issue: &Issue) {
+    let Some(branch) = issue.branch_name.as_deref().filter(|b| !b.is_empty()) else {
+        let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+        eprintln!("Linear has no branch name for {identifier}");
+        return;
+    };
+    if ctx.json || ctx.template.is_some() {
+        eprintln!("{branch}");
+    } else {
+        println!("{branch}");
+    }
+}
@@ -1,11 +1,16 @@
-use anyhow::Result;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
 use serde_json::json;
 
 use crate::api::client::LinearClient;
 use crate::api::types::*;
 use crate::api::{mutations, queries, resolve};
+use crate::cli::{StateType, TemplateArgs, TransitionArgs};
 use crate::config;
 use crate::interactive;
+use crate::template::{self, Template};
 
 struct StateOption {
     id: String,
@@ -18,11 +23,98 @@ impl std::fmt::Display for StateOption {
     }
 }
 
-pub async fn run(id: String, state: Option<String>) -> Result<()> {
+/// How the new state of each issue is chosen.
+#[derive(Clone, Copy)]
+enum Target<'a> {
+    Name(&'a str),
+    Type(StateType),
+    Prompt,
+}
+
+pub async fn run(
+    args: Vec<String>,
+    state_type: Option<StateType>,
+    json: bool,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
+    let (ids, state) = match state_type {
+        Some(_) => (args, None),
+        None => super::split_value(args, "state")?,
+    };
+    let target = match (state_type, &state) {
+        (Some(state_type), _) => Target::Type(state_type),
+        (None, Some(name)) => Target::Name(name),
+        (None, None) => Target::Prompt,
+    };
+    if matches!(target, Target::Prompt) && super::reads_stdin(&ids) {
+        bail!("Pass the state name when reading issue IDs from stdin");
+    }
+    apply(ids, target, json, template.as_ref()).await
+}
+
+/// `issue start|done|cancel|backlog|triage`: move issues to their team's state of that type.
+pub async fn transition(args: TransitionArgs, state_type: StateType) -> Result<()> {
+    let template = template::from_args(&args.template)?;
+    let target = Target::Type(state_type);
+    apply(args.ids, target, args.json, template.as_ref()).await
+}
+
+async fn apply(
+    ids: Vec<String>,
+    target: Target<'_>,
+    json: bool,
+    template: Option<&Template>,
+) -> Result<()> {
     let client = LinearClient::new(config::api_key()?);
+    let ids = super::read_ids(ids)?;
+    // Ask at most once per team when a type matches several states.
+    let picked = RefCell::new(HashMap::new());
+    super::for_each_id(ids, |id| {
+        set_state(&client, id, target, &picked, json, template)
+    })
+    .await
+}
+
+/// The team's workflow state of `state_type`. When there are several (e.g.
+/// "Done" and "Merged"), ask which one, or take the first in board order
+/// when not interactive.
+pub async fn state_of_type(
+    client: &LinearClient,
+    team_id: &str,
+    state_type: StateType,
+) -> Result<WorkflowState> {
+    let mut states = resolve::states_of_type(client, team_id, state_type.as_str()).await?;
+    if states.len() > 1 && interactive::is_tty() {
+        let options: Vec<StateOption> = states
+            .iter()
+            .map(|s| StateOption {
+                id: s.id.clone(),
+                name: s.name.clone().unwrap_or_default(),
+            })
+            .collect();
+        let prompt = format!("Which {} state?", state_type.as_str());
+        let selected = interactive::select(&prompt, options, "state")?;
+        states.retain(|s| s.id == selected.id);
+    }
+    states
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Team has no {} state", state_type.as_str()))
+}
 
+async fn set_state(
+    client: &LinearClient,
+    id: String,
+    target: Target<'_>,
+    picked: &RefCell<HashMap<String, (String, String)>>,
+    json: bool,
+    template: Option<&Template>,
+) -> Result<()> {
     // Fetch issue to get team ID
-    let issue_resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue_resp: IssueResponse = client
+        .query(queries::ISSUE_REF, json!({ "id": id }))
+        .await?;
     let team_id = issue_resp
         .issue
         .team
@@ -31,30 +123,45 @@ pub async fn run(id: String, state: Option<String>) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
 
     // Resolve state
-    let (state_id, state_name) = if let Some(name) = &state {
-        let sid = resolve::state_id(&client, &team_id, name).await?;
-        (sid, name.clone())
-    } else {
-        let states: WorkflowStatesResponse = client
-            .query(
-                queries::WORKFLOW_STATES,
-                json!({ "filter": { "team": { "id": { "eq": team_id } } } }),
-            )
-            .await?;
+    let (state_id, state_name) = match target {
+        Target::Name(name) => {
+            let sid = resolve::state_id(client, &team_id, name).await?;
+            (sid, name.to_string())
+        }
+        Target::Type(state_type) => {
+            let cached = picked.borrow().get(&team_id).cloned();
+            match cached {
+                Some(state) => state,
+                None => {
+                    let state = state_of_type(client, &team_id, state_type).await?;
+                    let state = (state.id, state.name.unwrap_or_default());
+                    picked.borrow_mut().insert(team_id, state.clone());
+                    state
+                }
+            }
+        }
+        Target::Prompt => {
+            let states: WorkflowStatesResponse = client
+                .query(
+                    queries::WORKFLOW_STATES,
+                    json!({ "filter": { "team": { "id": { "eq": team_id } } } }),
+                )
+                .await?;
 
-        let options: Vec<StateOption> = states
-            .workflow_states
-            .nodes
-            .into_iter()
-            .map(|s| StateOption {
-                id: s.id,
-                name: s.name.unwrap_or_default(),
-            })
-            .collect();
+            let options: Vec<StateOption> = states
+                .workflow_states
+                .nodes
+                .into_iter()
+                .map(|s| StateOption {
+                    id: s.id,
+                    name: s.name.unwrap_or_default(),
+                })
+                .collect();
 
-        let selected = interactive::select("Select state:", options, "state")?;
-        let name = selected.name.clone();
-        (selected.id, name)
+            let selected = interactive::select("Select state:", options, "state")?;
+            let name = selected.name.clone();
+            (selected.id, name)
+        }
     };
 
     let resp: IssueUpdateResponse = client
@@ -67,10 +174,11 @@ pub async fn run(id: String, state: Option<String>) -> Result<()> {
     if resp.issue_update.success {
         if let Some(issue) = resp.issue_update.issue {
             let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
-            println!("{identifier}: → {state_name}");
+            let message = format!("{identifier}: → {state_name}");
+            super::print_issue(&issue, json, template, &message)?;
         }
     } else {
-        anyhow::bail!("Failed to update issue state");
+        bail!("Failed to update issue state");
     }
 
     Ok(())
@@ -0,0 +1,56 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, resolve};
+use crate::cli::IssueTargets;
+use crate::config;
+
+/// Subscribe (or unsubscribe) `user`, defaulting to the viewer, to each issue.
+pub async fn run(target: IssueTargets, user: Option<String>, subscribe: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let user_id = resolve::user_id(&client, user.as_deref().unwrap_or("me")).await?;
+    let who = user.as_deref().unwrap_or("you");
+
+    let ids = super::read_ids(target.ids)?;
+    super::for_each_id(ids, |id| {
+        toggle(&client, id, &user_id, who, subscribe, target.json)
+    })
+    .await
+}
+
+async fn toggle(
+    client: &LinearClient,
+    id: String,
+    user_id: &str,
+    who: &str,
+    subscribe: bool,
+    json: bool,
+) -> Result<()> {
+    let variables = json!({ "id": id, "userId": user_id });
+    let result = if subscribe {
+        let resp: IssueSubscribeResponse =
+            client.query(mutations::ISSUE_SUBSCRIBE, variables).await?;
+        resp.issue_subscribe
+    } else {
+        let resp: IssueUnsubscribeResponse = client
+            .query(mutations::ISSUE_UNSUBSCRIBE, variables)
+            .await?;
+        resp.issue_unsubscribe
+    };
+    if !result.success {
+        bail!("Failed to update subscription");
+    }
+
+    if let Some(issue) = result.issue {
+        let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+        let message = if subscribe {
+            format!("Subscribed {who} to {identifier}")
+        } else {
+            format!("Unsubscribed {who} from {identifier}")
+        };
+        super::print_issue(&issue, json, None, &message)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::{Value, json};
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, paginate, queries, resolve};
+use crate::cli::IssueTargets;
+use crate::config;
+
+#[derive(Clone, Copy)]
+pub struct CopyOptions {
+    pub with_comments: bool,
+    pub with_sub_issues: bool,
+}
+
+/// The workflow states and labels available to a destination team, used to
+/// translate an issue's team-scoped fields.
+struct TeamMapping {
+    id: String,
+    key: String,
+    states: Vec<WorkflowState>,
+    labels: Vec<Label>,
+}
+
+impl TeamMapping {
+    async fn load(client: &LinearClient, key: &str) -> Result<Self> {
+        let id = resolve::team_id(client, key).await?;
+        let states: WorkflowStatesResponse = client
+            .query(
+                queries::WORKFLOW_STATES,
+                json!({ "filter": { "team": { "id": { "eq": id } } } }),
+            )
+            .await?;
+        // Workspace labels are shared; team labels must be matched by name.
+        let labels: LabelsResponse = client
+            .query(
+                queries::LABELS,
+                json!({
+                    "filter": {
+                        "or": [
+                            { "team": { "id": { "eq": id } } },
+                            { "team": { "null": true } },
+                        ]
+                    },
+                    "first": 250,
+                }),
+            )
+            .await?;
+        Ok(Self {
+            id,
+            key: key.to_uppercase(),
+            states: states.workflow_states.nodes,
+            labels: labels.issue_labels.nodes,
+        })
+    }
+
+    /// The state with the same name and type, else the first state of the same type.
+    fn state(&self, state: &WorkflowState) -> Option<&WorkflowState> {
+        let same_type = || {
+            self.states
+                .iter()
+                .filter(move |s| s.state_type.is_some() && s.state_type == state.state_type)
+        };
+        same_type()
+            .find(|s| {
+                s.name
+                    .as_deref()
+                    .zip(state.name.as_deref())
+                    .is_some_and(|(a, b)| a.eq_ignore_ascii_case(b))
+            })
+            .or_else(|| same_type().next())
+    }
+
+    /// The same label if it is shared, else one with the same name.
+    fn label(&self, label: &Label) -> Option<&Label> {
+        self.labels.iter().find(|l| l.id == label.id).or_else(|| {
+            self.labels.iter().find(|l| {
+                l.name
+                    .as_deref()
+                    .zip(label.name.as_deref())
+                    .is_some_and(|(a, b)| a.eq_ignore_ascii_case(b))
+            })
+        })
+    }
+
+    /// Set `stateId` and `labelIds` on `input`, returning what could not be mapped.
+    fn map_fields(&self, issue: &Issue, input: &mut Value) -> Vec<String> {
+        let mut notes = Vec::new();
+        if let Some(state) = &issue.state {
+            let name = state.name.as_deref().unwrap_or("?");
+            match self.state(state) {
+                Some(mapped) => input["stateId"] = json!(mapped.id),
+                None => notes.push(format!(
+                    "state '{name}' has no {} counterpart in {}; using the team's default",
+                    state.state_type.as_deref().unwrap_or("matching"),
+                    self.key
+                )),
+            }
+        }
+        if let Some(labels) = &issue.labels
+            && !labels.nodes.is_empty()
+        {
+            let mut label_ids = Vec::new();
+            for label in &labels.nodes {
+                match self.label(label) {
+                    Some(mapped) => label_ids.push(mapped.id.clone()),
+                    None => notes.push(format!(
+                        "label '{}' does not exist in {}; dropped",
+                        label.name.as_deref().unwrap_or("?"),
+                        self.key
+                    )),
+                }
+            }
+            input["labelIds"] = json!(label_ids);
+        }
+        notes
+    }
+}
+
+fn print_notes(identifier: &str, notes: &[String]) {
+    for note in notes {
+        eprintln!("{} {identifier}: {note}", "note:".yellow().bold());
+    }
+}
+
+fn same_team(issue: &Issue, mapping: &TeamMapping) -> bool {
+    issue.team.as_ref().is_some_and(|t| t.id == mapping.id)
+}
+
+/// Move issues to another team, mapping state by type and labels by name.
+pub async fn move_issues(target: IssueTargets, team: String) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let mapping = TeamMapping::load(&client, &team).await?;
+    let ids = super::read_ids(target.ids)?;
+    super::for_each_id(ids, |id| move_issue(&client, id, &mapping, target.json)).await
+}
+
+async fn move_issue(
+    client: &LinearClient,
+    id: String,
+    mapping: &TeamMapping,
+    json_output: bool,
+) -> Result<()> {
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+    let identifier = issue.identifier.as_deref().unwrap_or(&id).to_string();
+    if same_team(&issue, mapping) {
+        bail!("{identifier} is already in {}", mapping.key);
+    }
+
+    let mut input = json!({ "teamId": mapping.id });
+    let mut notes = mapping.map_fields(&issue, &mut input);
+    if let Some(cycle) = &issue.cycle {
+        // Cycles belong to a team.
+        input["cycleId"] = Value::Null;
+        let number = cycle.number.map(|n| format!(" {n:.0}")).unwrap_or_default();
+        notes.push(format!("removed from cycle{number}"));
+    }
+
+    let resp: IssueUpdateResponse = client
+        .query(
+            mutations::ISSUE_UPDATE,
+            json!({ "id": issue.id, "input": input }),
+        )
+        .await?;
+    if !resp.issue_update.success {
+        bail!("Failed to move {identifier}");
+    }
+    print_notes(&identifier, &notes);
+    if let Some(moved) = resp.issue_update.issue {
+        let new_identifier = moved.identifier.as_deref().unwrap_or(&moved.id);
+        let message = format!("Moved {identifier} → {new_identifier}");
+        super::print_issue(&moved, json_output, None, &message)?;
+    }
+    Ok(())
+}
+
+/// Copy issues into `team` (or their own team), optionally with comments and sub-issues.
+pub async fn copy_issues(
+    target: IssueTargets,
+    team: Option<String>,
+    options: CopyOptions,
+) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let mapping = match &team {
+        Some(team) => Some(TeamMapping::load(&client, team).await?),
+        None => None,
+    };
+    let ids = super::read_ids(target.ids)?;
+    super::for_each_id(ids, |id| {
+        copy_one(&client, id, mapping.as_ref(), options, target.json)
+    })
+    .await
+}
+
+async fn copy_one(
+    client: &LinearClient,
+    id: String,
+    mapping: Option<&TeamMapping>,
+    options: CopyOptions,
+    json_output: bool,
+) -> Result<()> {
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+    let own_team;
+    let mapping = match mapping {
+        Some(mapping) => mapping,
+        None => {
+            let key = issue
+                .team
+                .as_ref()
+                .and_then(|t| t.key.as_deref())
+                .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+            own_team = TeamMapping::load(client, key).await?;
+            &own_team
+        }
+    };
+
+    let copy = copy_issue(client, &issue, mapping, None, options).await?;
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let new_identifier = copy.identifier.as_deref().unwrap_or(&copy.id);
+    let message = format!("Copied {identifier} → {new_identifier}");
+    super::print_issue(&copy, json_output, None, &message)
+}
+
+async fn copy_issue(
+    client: &LinearClient,
+    issue: &Issue,
+    mapping: &TeamMapping,
+    parent_id: Option<&str>,
+    options: CopyOptions,
+) -> Result<Issue> {
+    let identifier = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let mut input = json!({
+        "teamId": mapping.id,
+        "title": issue.title.as_deref().unwrap_or(identifier),
+    });
+    if let Some(description) = &issue.description {
+        input["description"] = json!(description);
+    }
+    if let Some(priority) = issue.priority {
+        input["priority"] = json!(priority);
+    }
+    if let Some(estimate) = issue.estimate {
+        input["estimate"] = json!(estimate);
+    }
+    if let Some(due) = &issue.due_date {
+        input["dueDate"] = json!(due);
+    }
+    if let Some(assignee) = &issue.assignee {
+        input["assigneeId"] = json!(assignee.id);
+    }
+    if let Some(parent_id) = parent_id {
+        input["parentId"] = json!(parent_id);
+    }
+    let mut notes = mapping.map_fields(issue, &mut input);
+    // Projects and cycles are tied to the source team; only keep them there.
+    if same_team(issue, mapping) {
+        if let Some(project) = &issue.project {
+            input["projectId"] = json!(project.id);
+        }
+        if let Some(milestone) = &issue.project_milestone {
+            input["projectMilestoneId"] = json!(milestone.id);
+        }
+        if let Some(cycle) = &issue.cycle {
+            input["cycleId"] = json!(cycle.id);
+        }
+    } else {
+        if let Some(project) = &issue.project {
+            let name = project.name.as_deref().unwrap_or("?");
+            notes.push(format!("project '{name}' not copied to {}", mapping.key));
+        }
+        if issue.cycle.is_some() {
+            notes.push("cycle not copied".to_string());
+        }
+    }
+
+    let resp: IssueCreateResponse = client
+        .query(mutations::ISSUE_CREATE, json!({ "input": input }))
+        .await?;
+    let copy = match resp.issue_create {
+        MutationResult {
+            success: true,
+            issue: Some(copy),
+        } => copy,
+        _ => bail!("Failed to copy {identifier}"),
+    };
+    print_notes(identifier, &notes);
+
+    if options.with_comments {
+        copy_comments(client, issue, &copy.id).await?;
+    }
+    if options.with_sub_issues {
+        for child in paginate::issue_children(client, &issue.id).await? {
+            let resp: IssueResponse = client
+                .query(queries::ISSUE, json!({ "id": child.id }))
+                .await?;
+            let child = resp.issue;
+            let child_copy =
+                Box::pin(copy_issue(client, &child, mapping, Some(&copy.id), options)).await?;
+            eprintln!(
+                "Copied sub-issue {} → {}",
+                child.identifier.as_deref().unwrap_or(&child.id),
+                child_copy.identifier.as_deref().unwrap_or(&child_copy.id)
+            );
+        }
+    }
+    Ok(copy)
+}
+
+/// Recreate every comment on `to`, keeping threads. Comments are posted by the
+/// current user, so each one starts with its original author and date.
+async fn copy_comments(client: &LinearClient, from: &Issue, to: &str) -> Result<()> {
+    let identifier = from.identifier.as_deref().unwrap_or(&from.id);
+    let comments = paginate::issue_comments(client, &from.id, None).await?;
+    let mut copied: HashMap<String, String> = HashMap::new();
+    for comment in comments {
+        let author = comment
+            .user
+            .as_ref()
+            .and_then(|u| u.display_name.as_deref().or(u.name.as_deref()))
+            .unwrap_or("Unknown");
+        let date = comment
+            .created_at
+            .as_deref()
+            .and_then(|d| d.get(..10))
+            .unwrap_or("");
+        let body = comment.body.as_deref().unwrap_or("");
+        let body = format!("*{author} on {date}, copied from {identifier}:*\n\n{body}");
+
+        let mut input = json!({ "issueId": to, "body": body });
+        if let Some(parent) = comment.parent.as_ref().and_then(|p| copied.get(&p.id)) {
+            input["parentId"] = json!(parent);
+        }
+        let resp: CommentCreateResponse = client
+            .query(mutations::COMMENT_CREATE, json!({ "input": input }))
+            .await?;
+        match resp.comment_create.comment {
+            Some(new) if resp.comment_create.success => {
+                copied.insert(comment.id, new.id);
+            }
+            _ => bail!("Failed to copy a comment from {identifier}"),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::{Connection, Issue, IssueResponse};
+use crate::api::{paginate, queries};
+use crate::config;
+use crate::output;
+
+pub async fn run(id: String, json_output: bool) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let resp: IssueResponse = client
+        .query(queries::ISSUE_REF, json!({ "id": id }))
+        .await?;
+    let mut root = resp.issue;
+    load_children(&client, &mut root).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&root)?);
+    } else {
+        output::issue_tree(&root);
+    }
+    Ok(())
+}
+
+/// Fill `issue.children` with its whole sub-issue hierarchy, one request per
+/// issue that has sub-issues.
+async fn load_children(client: &LinearClient, issue: &mut Issue) -> Result<()> {
+    let mut children = paginate::issue_children(client, &issue.id).await?;
+    for child in &mut children {
+        let has_children = child.children.take().is_some_and(|c| !c.nodes.is_empty());
+        if has_children {
+            Box::pin(load_children(client, child)).await?;
+        } else {
+            child.children = Some(Connection {
+                nodes: Vec::new(),
+                page_info: None,
+            });
+        }
+    }
+    issue.children = Some(Connection {
+        nodes: children,
+        page_info: None,
+    });
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anyhow::{Result, bail};
+use serde_json::json;
+
+use crate::api::client::LinearClient;
+use crate::api::types::*;
+use crate::api::{mutations, queries};
+use crate::cli::RelationKind;
+use crate::commands::issue::relate;
+use crate::config;
+
+pub async fn run(id: String, other: String, relation: Option<RelationKind>) -> Result<()> {
+    let client = LinearClient::new(config::api_key()?);
+    let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let issue = resp.issue;
+
+    let is_other = |related: Option<&Issue>| {
+        related.is_some_and(|r| {
+            r.id == other
+                || r.identifier
+                    .as_deref()
+                    .is_some_and(|i| i.eq_ignore_ascii_case(&other))
+        })
+    };
+    // Symmetric types (related, similar) match in either direction.
+    let wanted = relation.map(relate::api_type);
+    let matches = |relation_type: Option<&str>, inbound: bool| match wanted {
+        None => true,
+        Some((wanted_type, swap)) => {
+            relation_type == Some(wanted_type)
+                && (swap == inbound || matches!(wanted_type, "related" | "similar"))
+        }
+    };
+
+    let outbound = issue
+        .relations
+        .iter()
+        .flat_map(|c| &c.nodes)
+        .filter(|r| is_other(r.related_issue.as_deref()))
+        .filter(|r| matches(r.relation_type.as_deref(), false));
+    let inbound = issue
+        .inverse_relations
+        .iter()
+        .flat_map(|c| &c.nodes)
+        .filter(|r| is_other(r.issue.as_deref()))
+        .filter(|r| matches(r.relation_type.as_deref(), true));
+    let to_delete: Vec<&IssueRelation> = outbound.chain(inbound).collect();
+
+    if to_delete.is_empty() {
+        bail!("No matching relation between {id} and {other}");
+    }
+
+    for relation in to_delete {
+        let resp: IssueRelationDeleteResponse = client
+            .query(
+                mutations::ISSUE_RELATION_DELETE,
+                json!({ "id": relation.id }),
+            )
+            .await?;
+        if !resp.issue_relation_delete.success {
+            bail!("Failed to remove relation {}", relation.id);
+        }
+        let kind = relation.relation_type.as_deref().unwrap_or("related");
+        println!("Removed {kind} relation between {id} and {other}");
+    }
+    Ok(())
+}
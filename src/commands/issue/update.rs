@@ -1,16 +1,70 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
 use anyhow::Result;
-use serde_json::json;
+use serde_json::{Value, json};
 
 use crate::api::client::LinearClient;
 use crate::api::types::*;
 use crate::api::{mutations, queries, resolve};
 use crate::cli::UpdateArgs;
 use crate::config;
+use crate::editor;
+use crate::interactive;
+use crate::template::{self, Template};
 
 pub async fn run(args: UpdateArgs) -> Result<()> {
+    let template = template::from_args(&args.template)?;
+    if super::reads_stdin(&args.ids) {
+        let description_on_stdin = args
+            .description_file
+            .as_deref()
+            .is_some_and(|path| path.as_os_str() == "-");
+        if description_on_stdin || args.edit_description {
+            anyhow::bail!(
+                "--description-file - and --edit-description cannot be used when reading issue IDs from stdin"
+            );
+        }
+    }
     let client = LinearClient::new(config::api_key()?);
-    let issue_id = args.id;
+    let ids = super::read_ids(args.ids.clone())?;
+    // Read a piped description and resolve subscribers once, not once per issue.
+    let description_file = match &args.description_file {
+        Some(path) => Some(interactive::read_file(path)?),
+        None => None,
+    };
+    let mut subscriber_ids = Vec::new();
+    for user in &args.subscriber {
+        let uid = resolve::user_id(&client, user).await?;
+        if !subscriber_ids.contains(&uid) {
+            subscriber_ids.push(uid);
+        }
+    }
+    // `--state-type` asks at most once per team.
+    let picked = RefCell::new(HashMap::new());
+    super::for_each_id(ids, |id| {
+        update(
+            &client,
+            &args,
+            description_file.as_deref(),
+            &subscriber_ids,
+            &picked,
+            template.as_ref(),
+            id,
+        )
+    })
+    .await
+}
 
+async fn update(
+    client: &LinearClient,
+    args: &UpdateArgs,
+    description_file: Option<&str>,
+    subscriber_ids: &[String],
+    picked: &RefCell<HashMap<String, String>>,
+    template: Option<&Template>,
+    issue_id: String,
+) -> Result<()> {
     let mut input = json!({});
     let mut has_update = false;
 
@@ -19,18 +73,18 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
         has_update = true;
     }
 
-    if let Some(desc) = &args.description {
-        input["description"] = json!(desc);
-        has_update = true;
-    }
-
     if let Some(p) = args.priority {
         input["priority"] = json!(p);
         has_update = true;
     }
 
-    let needs_issue_context =
-        args.state.is_some() || !args.add_label.is_empty() || !args.remove_label.is_empty();
+    let needs_issue_context = args.state.is_some()
+        || args.state_type.is_some()
+        || args.edit_description
+        || !args.add_label.is_empty()
+        || !args.remove_label.is_empty()
+        || args.cycle.is_some()
+        || (args.milestone.is_some() && args.project.is_none());
     let issue_context = if needs_issue_context {
         let issue_resp: IssueResponse = client
             .query(queries::ISSUE, json!({ "id": &issue_id }))
@@ -40,22 +94,59 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
         None
     };
 
+    let description = if args.edit_description {
+        let issue = issue_context
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Unable to load current description"))?;
+        let current = issue.description.clone().unwrap_or_default();
+        let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+        let edited = editor::compose(&current, &format!("Description of {id}."), "description")?;
+        (edited.trim_end() != current.trim_end()).then_some(edited)
+    } else if let Some(description) = description_file {
+        Some(description.to_string())
+    } else {
+        args.description.clone()
+    };
+    if let Some(desc) = description {
+        input["description"] = json!(desc);
+        has_update = true;
+    }
+
     if let Some(state_name) = &args.state {
         let team_id = issue_context
             .as_ref()
             .and_then(|issue| issue.team.as_ref())
             .map(|t| t.id.clone())
             .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
-        let sid = resolve::state_id(&client, &team_id, state_name).await?;
+        let sid = resolve::state_id(client, &team_id, state_name).await?;
         input["stateId"] = json!(sid);
         has_update = true;
     }
 
+    if let Some(state_type) = args.state_type {
+        let team_id = issue_context
+            .as_ref()
+            .and_then(|issue| issue.team.as_ref())
+            .map(|t| t.id.clone())
+            .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+        let cached = picked.borrow().get(&team_id).cloned();
+        let state_id = match cached {
+            Some(state_id) => state_id,
+            None => {
+                let state = super::state::state_of_type(client, &team_id, state_type).await?;
+                picked.borrow_mut().insert(team_id, state.id.clone());
+                state.id
+            }
+        };
+        input["stateId"] = json!(state_id);
+        has_update = true;
+    }
+
     if let Some(assignee) = &args.assignee {
         if assignee.is_empty() {
             input["assigneeId"] = json!(null);
         } else {
-            let uid = resolve::user_id(&client, assignee).await?;
+            let uid = resolve::user_id(client, assignee).await?;
             input["assigneeId"] = json!(uid);
         }
         has_update = true;
@@ -75,14 +166,14 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
                 .unwrap_or_default();
 
             for label in &args.add_label {
-                let lid = resolve::label_id(&client, label).await?;
+                let lid = resolve::label_id(client, label).await?;
                 if !label_ids.iter().any(|id| id == &lid) {
                     label_ids.push(lid);
                 }
             }
 
             for label in &args.remove_label {
-                let lid = resolve::label_id(&client, label).await?;
+                let lid = resolve::label_id(client, label).await?;
                 label_ids.retain(|id| id != &lid);
             }
 
@@ -91,27 +182,146 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
         has_update = true;
     }
 
-    if !has_update {
+    let project_id = match &args.project {
+        Some(project) => Some(resolve::project_id(client, project).await?),
+        None => None,
+    };
+    if let Some(project_id) = &project_id {
+        input["projectId"] = json!(project_id);
+        has_update = true;
+    } else if args.no_project {
+        input["projectId"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(milestone) = &args.milestone {
+        let project_id = project_id
+            .or_else(|| {
+                issue_context
+                    .as_ref()
+                    .and_then(|issue| issue.project.as_ref())
+                    .map(|p| p.id.clone())
+            })
+            .ok_or_else(|| {
+                anyhow::anyhow!("Issue has no project; pass --project with --milestone")
+            })?;
+        let mid = resolve::milestone_id(client, &project_id, milestone).await?;
+        input["projectMilestoneId"] = json!(mid);
+        has_update = true;
+    } else if args.no_milestone {
+        input["projectMilestoneId"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(cycle) = &args.cycle {
+        let team_id = issue_context
+            .as_ref()
+            .and_then(|issue| issue.team.as_ref())
+            .map(|t| t.id.clone())
+            .ok_or_else(|| anyhow::anyhow!("Issue has no team"))?;
+        input["cycleId"] = json!(resolve::cycle_id(client, &team_id, cycle).await?);
+        has_update = true;
+    } else if args.no_cycle {
+        input["cycleId"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(estimate) = args.estimate {
+        input["estimate"] = json!(estimate);
+        has_update = true;
+    } else if args.no_estimate {
+        input["estimate"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(due) = args.due {
+        input["dueDate"] = json!(due.to_string());
+        has_update = true;
+    } else if args.no_due {
+        input["dueDate"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(parent) = &args.parent {
+        input["parentId"] = json!(resolve::issue_id(client, parent).await?);
+        has_update = true;
+    } else if args.no_parent {
+        input["parentId"] = json!(null);
+        has_update = true;
+    }
+
+    if let Some(sort_order) = args.sort_order {
+        input["sortOrder"] = json!(sort_order);
+        has_update = true;
+    }
+
+    if !has_update && subscriber_ids.is_empty() {
+        if args.edit_description {
+            println!("No changes.");
+            return Ok(());
+        }
         anyhow::bail!(
-            "No updates specified. Use --title, --description, --priority, --state, --assignee, --add-label, --remove-label, or --clear-labels."
+            "No updates specified. Use --title, --description, --priority, --state, --state-type, --assignee, --add-label, --project, --cycle, --estimate, --due, --parent, or see --help."
         );
     }
 
+    let mut issue = None;
+    if has_update {
+        issue = Some(apply(client, &issue_id, input).await?);
+    }
+    // Subscribing one user at a time keeps the existing subscribers, however many.
+    for user_id in subscriber_ids {
+        let resp: IssueSubscribeResponse = client
+            .query(
+                mutations::ISSUE_SUBSCRIBE,
+                json!({ "id": &issue_id, "userId": user_id }),
+            )
+            .await?;
+        issue = match resp.issue_subscribe {
+            MutationResult {
+                success: true,
+                issue: Some(issue),
+            } => Some(issue),
+            _ => anyhow::bail!("Failed to subscribe user"),
+        };
+    }
+    if let Some(issue) = issue {
+        print_updated(&issue, args.json, template)?;
+    }
+    Ok(())
+}
+
+/// Run `issueUpdate` with a prepared input and report the result.
+pub async fn send(
+    client: &LinearClient,
+    issue_id: &str,
+    input: Value,
+    json_output: bool,
+    template: Option<&Template>,
+) -> Result<()> {
+    let issue = apply(client, issue_id, input).await?;
+    print_updated(&issue, json_output, template)
+}
+
+async fn apply(client: &LinearClient, issue_id: &str, input: Value) -> Result<Issue> {
     let resp: IssueUpdateResponse = client
         .query(
             mutations::ISSUE_UPDATE,
-            json!({ "id": &issue_id, "input": input }),
+            json!({ "id": issue_id, "input": input }),
         )
         .await?;
 
-    if resp.issue_update.success {
-        if let Some(issue) = resp.issue_update.issue {
-            let id = issue.identifier.as_deref().unwrap_or(&issue.id);
-            println!("Updated {id}");
-        }
-    } else {
-        anyhow::bail!("Failed to update issue");
+    match resp.issue_update {
+        MutationResult {
+            success: true,
+            issue: Some(issue),
+        } => Ok(issue),
+        _ => anyhow::bail!("Failed to update issue"),
     }
+}
 
-    Ok(())
+fn print_updated(issue: &Issue, json_output: bool, template: Option<&Template>) -> Result<()> {
+    let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let message = format!("Updated {id}");
+    super::print_issue(issue, json_output, template, &message)
 }
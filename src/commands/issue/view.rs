@@ -1,19 +1,71 @@
 use anyhow::Result;
+use colored::Colorize;
 use serde_json::json;
 
 use crate::api::client::LinearClient;
-use crate::api::queries;
-use crate::api::types::IssueResponse;
+use crate::api::types::{Connection, IssueResponse};
+use crate::api::{paginate, queries};
+use crate::cli::{CommentLimit, TemplateArgs};
 use crate::config;
 use crate::output;
+use crate::template::{self, Template};
 
-pub async fn run(id: String, json: bool) -> Result<()> {
+pub async fn run(
+    ids: Vec<String>,
+    json: bool,
+    raw: bool,
+    comments: CommentLimit,
+    template_args: TemplateArgs,
+) -> Result<()> {
+    let template = template::from_args(&template_args)?;
     let client = LinearClient::new(config::api_key()?);
+    let ids = super::read_ids(ids)?;
+    super::for_each_id(ids, |id| {
+        view(&client, id, json, raw, comments, template.as_ref())
+    })
+    .await
+}
+
+async fn view(
+    client: &LinearClient,
+    id: String,
+    json: bool,
+    raw: bool,
+    comments: CommentLimit,
+    template: Option<&Template>,
+) -> Result<()> {
     let resp: IssueResponse = client.query(queries::ISSUE, json!({ "id": id })).await?;
+    let mut issue = resp.issue;
+
+    let limit = match comments {
+        CommentLimit::None => None,
+        CommentLimit::All => Some(None),
+        CommentLimit::Count(n) => Some(Some(n)),
+    };
+    if let Some(limit) = limit {
+        // One extra comment tells whether the limit cut any off.
+        let mut nodes = paginate::issue_comments(client, &issue.id, limit.map(|n| n + 1)).await?;
+        if let Some(n) = limit {
+            if nodes.len() > n {
+                nodes.truncate(n);
+                eprintln!(
+                    "{} showing the first {n} comments; pass --comments all to see every one",
+                    "note:".yellow().bold()
+                );
+            }
+        }
+        issue.comments = Some(Connection {
+            nodes,
+            page_info: None,
+        });
+    }
+
     if json {
-        println!("{}", serde_json::to_string_pretty(&resp.issue)?);
+        println!("{}", serde_json::to_string_pretty(&issue)?);
+    } else if let Some(template) = template {
+        template::print_one(template, &issue)?;
     } else {
-        output::issue_detail(&resp.issue);
+        output::issue_detail(&issue, raw);
     }
     Ok(())
 }
@@ -1,5 +1,6 @@
 pub mod api;
 pub mod auth;
+pub mod comment;
 pub mod issue;
 pub mod me;
 pub mod team;
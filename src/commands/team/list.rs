@@ -4,12 +4,19 @@ use serde_json::json;
 use crate::api::client::LinearClient;
 use crate::api::queries;
 use crate::api::types::TeamsResponse;
+use crate::cli::TemplateArgs;
 use crate::config;
 use crate::output;
+use crate::template;
 
-pub async fn run() -> Result<()> {
+pub async fn run(template_args: TemplateArgs) -> Result<()> {
+    let template = template::from_args(&template_args)?;
     let client = LinearClient::new(config::api_key()?);
     let resp: TeamsResponse = client.query(queries::TEAMS, json!({})).await?;
-    output::team_table(&resp.teams.nodes);
+    if let Some(template) = &template {
+        template::print_each(template, &resp.teams.nodes)?;
+    } else {
+        output::team_table(&resp.teams.nodes);
+    }
     Ok(())
 }
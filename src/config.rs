@@ -1,10 +1,39 @@
 use anyhow::{Context, Result, bail};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Config {
     pub api_key: Option<String>,
+    /// Set to `false` to never page long output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pager: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
+}
+
+/// `[theme]` table, e.g.
+///
+/// ```toml
+/// [theme]
+/// preset = "light"
+/// label_colors = true
+/// states = { started = "#5e6ad2 bold", canceled = "dimmed strikethrough" }
+/// priorities = { urgent = "bright red bold" }
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Base palette: "dark" (default) or "light".
+    pub preset: Option<String>,
+    /// Styles keyed by workflow state type (triage, backlog, unstarted, started, completed, canceled).
+    #[serde(default)]
+    pub states: HashMap<String, String>,
+    /// Styles keyed by priority (urgent, high, medium, low, none).
+    #[serde(default)]
+    pub priorities: HashMap<String, String>,
+    /// Color labels with their Linear color (default true).
+    pub label_colors: Option<bool>,
 }
 
 fn config_dir() -> Result<PathBuf> {
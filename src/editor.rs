@@ -0,0 +1,116 @@
+use anyhow::{Context, Result, bail};
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_EDITOR: &str = "vi";
+
+/// Open `initial` in `$VISUAL`/`$EDITOR` and return the saved text.
+pub fn edit(initial: &str) -> Result<String> {
+    let editor = editor_command();
+    let path = create_temp(initial)?;
+
+    let status = shell(&editor, &path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{editor}'"));
+    let text = status.and_then(|status| {
+        if !status.success() {
+            bail!("Editor '{editor}' exited with {status}");
+        }
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+    });
+    let _ = std::fs::remove_file(&path);
+    text
+}
+
+/// Opens the help block `compose` appends. It and everything after it is
+/// dropped on save, so HTML comments in the text itself are kept.
+const HELP_MARKER: &str = "<!-- linear-cli: everything from this line down is ignored -->";
+
+/// Edit `initial` above a help note and return the text without it. An empty
+/// result aborts with an error naming `what`.
+pub fn compose(initial: &str, help: &str, what: &str) -> Result<String> {
+    let mut buffer = initial.trim_end().to_string();
+    buffer.push_str(&format!("\n\n{HELP_MARKER}\n<!--\n"));
+    for line in help.lines() {
+        buffer.push_str(&format!("  {line}\n"));
+    }
+    buffer.push_str("  Save an empty file to abort.\n-->\n");
+
+    let text = strip_help(&edit(&buffer)?);
+    if text.trim().is_empty() {
+        bail!("Aborting: empty {what}");
+    }
+    Ok(text)
+}
+
+/// Cut the text at the last help marker.
+fn strip_help(text: &str) -> String {
+    let text = text.rfind(HELP_MARKER).map_or(text, |at| &text[..at]);
+    text.trim_start_matches('\n').trim_end().to_string()
+}
+
+fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// Write `contents` to a new file in the temp directory. `create_new` refuses
+/// existing paths, so a file or symlink planted at a guessed name is never
+/// followed.
+fn create_temp(contents: &str) -> Result<PathBuf> {
+    let dir = std::env::temp_dir();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    for attempt in 0..16 {
+        let path = dir.join(format!(
+            "linear-{}-{nanos}-{attempt}.md",
+            std::process::id()
+        ));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = match options.open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to create {}", path.display()));
+            }
+        };
+        file.write_all(contents.as_bytes())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        return Ok(path);
+    }
+    bail!("Failed to create a temporary file in {}", dir.display())
+}
+
+/// The editor value may carry arguments (`code --wait`), so run it via the shell.
+#[cfg(unix)]
+fn shell(editor: &str, path: &std::path::Path) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(path);
+    cmd
+}
+
+#[cfg(not(unix))]
+fn shell(editor: &str, path: &std::path::Path) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C")
+        .arg(format!("{editor} \"{}\"", path.display()));
+    cmd
+}
@@ -0,0 +1,321 @@
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+
+use crate::api::types::Issue;
+
+const DELIMITER: &str = "+++";
+const PRIORITIES: [&str; 5] = ["none", "urgent", "high", "medium", "low"];
+
+/// Issue fields as written in TOML front matter.
+///
+/// `None` means the key is absent (leave unchanged); an empty string or empty
+/// `labels` list clears the field. `team` and `parent` are only used when
+/// creating issues from files.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IssueFields {
+    pub team: Option<String>,
+    pub title: Option<String>,
+    pub state: Option<String>,
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub project: Option<String>,
+    pub estimate: Option<String>,
+    pub due: Option<String>,
+    pub parent: Option<String>,
+}
+
+const KEYS: &[&str] = &[
+    "team", "title", "state", "priority", "assignee", "labels", "project", "estimate", "due",
+    "parent",
+];
+
+impl IssueFields {
+    /// The current values of an issue, with every key present.
+    pub fn from_issue(issue: &Issue) -> Self {
+        let name = |value: Option<&String>| value.cloned().unwrap_or_default();
+        Self {
+            title: Some(name(issue.title.as_ref())),
+            state: Some(name(issue.state.as_ref().and_then(|s| s.name.as_ref()))),
+            priority: Some(priority_name(issue.priority.unwrap_or(0)).to_string()),
+            assignee: Some(
+                issue
+                    .assignee
+                    .as_ref()
+                    .and_then(|u| u.display_name.as_ref().or(u.name.as_ref()))
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            labels: Some(
+                issue
+                    .labels
+                    .as_ref()
+                    .map(|c| c.nodes.iter().filter_map(|l| l.name.clone()).collect())
+                    .unwrap_or_default(),
+            ),
+            project: Some(name(issue.project.as_ref().and_then(|p| p.name.as_ref()))),
+            estimate: Some(issue.estimate.map(format_estimate).unwrap_or_default()),
+            due: Some(name(issue.due_date.as_ref())),
+            ..Self::default()
+        }
+    }
+
+    /// Render as a `+++` TOML block followed by `body`.
+    pub fn render(&self, body: &str) -> String {
+        let mut out = String::from(DELIMITER);
+        out.push('\n');
+        out.push_str("# Empty values clear a field. Priority: none, urgent, high, medium, low.\n");
+        push_text(&mut out, "team", &self.team);
+        push_text(&mut out, "title", &self.title);
+        push_text(&mut out, "state", &self.state);
+        push_text(&mut out, "priority", &self.priority);
+        push_text(&mut out, "assignee", &self.assignee);
+        if let Some(labels) = &self.labels {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|l| toml::Value::from(l.as_str()).to_string())
+                .collect();
+            out.push_str(&format!("labels = [{}]\n", labels.join(", ")));
+        }
+        push_text(&mut out, "project", &self.project);
+        match &self.estimate {
+            Some(estimate) if !estimate.is_empty() => {
+                out.push_str(&format!("estimate = {estimate}\n"));
+            }
+            Some(_) => out.push_str("estimate = \"\"\n"),
+            None => {}
+        }
+        push_text(&mut out, "due", &self.due);
+        push_text(&mut out, "parent", &self.parent);
+        out.push_str(DELIMITER);
+        out.push_str("\n\n");
+        out.push_str(body_text(body));
+        out.push('\n');
+        out
+    }
+
+    /// Parse a document produced by [`IssueFields::render`] (or written by hand).
+    pub fn parse(text: &str) -> Result<(Self, String)> {
+        let (front, body) = split(text)?;
+        let table: toml::Table = front.parse().context("Invalid TOML front matter")?;
+
+        if let Some(unknown) = table.keys().find(|k| !KEYS.contains(&k.as_str())) {
+            bail!(
+                "Unknown front matter key '{unknown}'. Supported: {}",
+                KEYS.join(", ")
+            );
+        }
+
+        let fields = Self {
+            team: text_field(&table, "team")?,
+            title: text_field(&table, "title")?,
+            state: text_field(&table, "state")?,
+            priority: text_field(&table, "priority")?,
+            assignee: text_field(&table, "assignee")?,
+            labels: list_field(&table, "labels")?,
+            project: text_field(&table, "project")?,
+            estimate: text_field(&table, "estimate")?,
+            due: text_field(&table, "due")?,
+            parent: text_field(&table, "parent")?,
+        };
+        if let Some(priority) = &fields.priority {
+            parse_priority(priority)?;
+        }
+        if let Some(due) = fields.due.as_deref().filter(|d| !d.is_empty()) {
+            parse_due(due)?;
+        }
+        Ok((fields, body))
+    }
+}
+
+fn push_text(out: &mut String, key: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        out.push_str(&format!("{key} = {}\n", toml::Value::from(value.as_str())));
+    }
+}
+
+/// Split `+++\n<front matter>\n+++\n<body>`.
+fn split(text: &str) -> Result<(&str, String)> {
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    let Some(rest) = text.strip_prefix(DELIMITER) else {
+        bail!("Missing '{DELIMITER}' front matter at the start of the file");
+    };
+    let rest = rest.trim_start_matches([' ', '\t', '\r']);
+    let Some(rest) = rest.strip_prefix('\n') else {
+        bail!("Expected a newline after the opening '{DELIMITER}'");
+    };
+    let end = rest
+        .match_indices(DELIMITER)
+        .find(|(i, _)| *i == 0 || rest[..*i].ends_with('\n'))
+        .map(|(i, _)| i)
+        .with_context(|| format!("Missing closing '{DELIMITER}' after front matter"))?;
+    let body = &rest[end + DELIMITER.len()..];
+    // Only the rest of the delimiter line goes; the body keeps its indentation.
+    let body = match body.find('\n') {
+        Some(newline) if body[..newline].trim().is_empty() => &body[newline + 1..],
+        _ => body.trim_start_matches([' ', '\t', '\r']),
+    };
+    Ok((&rest[..end], body_text(body).to_string()))
+}
+
+/// A body as it sits below the front matter: without the blank lines that
+/// separate it from the `+++` line, nor trailing whitespace. Indentation of the
+/// first line is kept, as it can start a code block.
+pub fn body_text(body: &str) -> &str {
+    let start = body
+        .split_inclusive('\n')
+        .take_while(|line| line.trim().is_empty())
+        .map(str::len)
+        .sum();
+    body[start..].trim_end()
+}
+
+fn text_field(table: &toml::Table, key: &str) -> Result<Option<String>> {
+    let Some(value) = table.get(key) else {
+        return Ok(None);
+    };
+    let text = match value {
+        toml::Value::String(s) => s.trim().to_string(),
+        toml::Value::Integer(n) => n.to_string(),
+        toml::Value::Float(f) => format_estimate(*f),
+        toml::Value::Datetime(dt) => dt.to_string(),
+        other => bail!("'{key}' must be a string, got {}", other.type_str()),
+    };
+    Ok(Some(text))
+}
+
+fn list_field(table: &toml::Table, key: &str) -> Result<Option<Vec<String>>> {
+    let Some(value) = table.get(key) else {
+        return Ok(None);
+    };
+    let items = match value {
+        toml::Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                toml::Value::String(s) => Ok(s.trim().to_string()),
+                other => bail!("'{key}' entries must be strings, got {}", other.type_str()),
+            })
+            .collect::<Result<Vec<_>>>()?,
+        toml::Value::String(s) if s.trim().is_empty() => Vec::new(),
+        toml::Value::String(s) => vec![s.trim().to_string()],
+        other => bail!(
+            "'{key}' must be a list of strings, got {}",
+            other.type_str()
+        ),
+    };
+    Ok(Some(items.into_iter().filter(|s| !s.is_empty()).collect()))
+}
+
+pub fn priority_name(priority: i32) -> &'static str {
+    PRIORITIES
+        .get(priority as usize)
+        .copied()
+        .unwrap_or(PRIORITIES[0])
+}
+
+/// Accept a priority name (`high`) or number (`2`).
+pub fn parse_priority(value: &str) -> Result<i32> {
+    let lower = value.trim().to_lowercase();
+    if lower.is_empty() {
+        return Ok(0);
+    }
+    if let Some(index) = PRIORITIES.iter().position(|p| *p == lower) {
+        return Ok(index as i32);
+    }
+    match lower.parse::<i32>() {
+        Ok(n) if (0..=4).contains(&n) => Ok(n),
+        _ => bail!(
+            "Invalid priority '{value}'. Use one of: {} (or 0-4)",
+            PRIORITIES.join(", ")
+        ),
+    }
+}
+
+pub fn parse_due(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .with_context(|| format!("Invalid due date '{value}'. Use YYYY-MM-DD"))
+}
+
+fn format_estimate(estimate: f64) -> String {
+    if estimate.fract() == 0.0 {
+        format!("{estimate:.0}")
+    } else {
+        estimate.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> IssueFields {
+        IssueFields {
+            title: Some("Fix \"login\" page".to_string()),
+            state: Some("In Progress".to_string()),
+            priority: Some("high".to_string()),
+            assignee: Some(String::new()),
+            labels: Some(vec!["bug".to_string(), "ui".to_string()]),
+            estimate: Some("2.5".to_string()),
+            due: Some("2024-03-05".to_string()),
+            ..IssueFields::default()
+        }
+    }
+
+    #[test]
+    fn render_then_parse_round_trips() {
+        let body = "Steps:\n\n1. Open the page\n2. Log in";
+        let (parsed, parsed_body) = IssueFields::parse(&fields().render(body)).unwrap();
+        assert_eq!(parsed, fields());
+        assert_eq!(parsed_body, body);
+    }
+
+    #[test]
+    fn body_keeps_leading_indentation() {
+        let body = "    indented code\n    more code\n\nText";
+        let (_, parsed_body) = IssueFields::parse(&fields().render(body)).unwrap();
+        assert_eq!(parsed_body, body);
+    }
+
+    #[test]
+    fn empty_body_round_trips() {
+        let (_, parsed_body) = IssueFields::parse(&fields().render("")).unwrap();
+        assert_eq!(parsed_body, "");
+    }
+
+    #[test]
+    fn split_finds_the_closing_delimiter_on_its_own_line() {
+        let text = "\u{feff}+++\ntitle = \"a+++b\"\n+++\nBody +++ text\n";
+        let (front, body) = split(text).unwrap();
+        assert_eq!(front, "title = \"a+++b\"\n");
+        assert_eq!(body, "Body +++ text");
+    }
+
+    #[test]
+    fn split_handles_crlf() {
+        let (front, body) = split("+++\r\ntitle = \"A\"\r\n+++\r\n\r\n  Body\r\n").unwrap();
+        assert_eq!(front, "title = \"A\"\r\n");
+        assert_eq!(body, "  Body");
+    }
+
+    #[test]
+    fn split_rejects_missing_delimiters() {
+        assert!(split("title = \"A\"").is_err());
+        assert!(split("+++\ntitle = \"A\"\n").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_keys_and_bad_values() {
+        assert!(IssueFields::parse("+++\ntitel = \"A\"\n+++\n").is_err());
+        assert!(IssueFields::parse("+++\npriority = \"asap\"\n+++\n").is_err());
+        assert!(IssueFields::parse("+++\ndue = \"tomorrow\"\n+++\n").is_err());
+    }
+
+    #[test]
+    fn absent_keys_stay_unset() {
+        let (parsed, body) = IssueFields::parse("+++\ntitle = \"A\"\n+++\nText").unwrap();
+        assert_eq!(parsed.title.as_deref(), Some("A"));
+        assert_eq!(parsed.state, None);
+        assert_eq!(parsed.labels, None);
+        assert_eq!(body, "Text");
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{Context, Result, bail};
+use std::process::{Command, Output};
+
+/// Run `git` with `args` in the current directory.
+fn git(args: &[&str]) -> Result<Output> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git; is it installed?")
+}
+
+fn in_work_tree() -> bool {
+    git(&["rev-parse", "--is-inside-work-tree"])
+        .is_ok_and(|out| out.status.success() && out.stdout.starts_with(b"true"))
+}
+
+/// The checked-out branch, or `None` outside a repository or on a detached HEAD.
+pub fn current_branch() -> Result<Option<String>> {
+    if !in_work_tree() {
+        return Ok(None);
+    }
+    let out = git(&["symbolic-ref", "--quiet", "--short", "HEAD"])?;
+    let branch = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    Ok((out.status.success() && !branch.is_empty()).then_some(branch))
+}
+
+/// Fail unless the current directory is inside a git work tree.
+pub fn require_work_tree() -> Result<()> {
+    if !in_work_tree() {
+        bail!("Not inside a git repository (use --no-checkout to just print the branch name)");
+    }
+    Ok(())
+}
+
+/// How `checkout` got onto the branch.
+pub enum Checkout {
+    /// The local branch already existed.
+    Existing,
+    /// A new local branch tracking this remote branch (e.g. `origin/eng-1-fix`).
+    Tracking(String),
+    /// A new branch from HEAD, as nobody has pushed one yet.
+    Created,
+}
+
+/// Check out `branch`: the local branch if there is one, else a branch
+/// tracking a remote branch of that name (fetched if needed), else a new
+/// branch from HEAD.
+pub fn checkout(branch: &str) -> Result<Checkout> {
+    require_work_tree()?;
+    let local = format!("refs/heads/{branch}");
+    let exists = git(&["show-ref", "--verify", "--quiet", &local])?
+        .status
+        .success();
+    let (out, outcome) = if exists {
+        (git(&["checkout", branch])?, Checkout::Existing)
+    } else if let Some(remote) = remote_branch(branch)? {
+        let out = git(&["checkout", "-b", branch, "--track", &remote])?;
+        (out, Checkout::Tracking(remote))
+    } else {
+        (git(&["checkout", "-b", branch])?, Checkout::Created)
+    };
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        bail!("git checkout {branch} failed: {}", stderr.trim());
+    }
+    Ok(outcome)
+}
+
+/// `<remote>/<branch>` if a remote has `branch`, fetching it when it is not
+/// known locally yet.
+fn remote_branch(branch: &str) -> Result<Option<String>> {
+    if let Some(remote) = tracking_ref(branch)? {
+        return Ok(Some(remote));
+    }
+    let remotes = git(&["remote"])?;
+    for remote in String::from_utf8_lossy(&remotes.stdout).lines() {
+        // Fetching a named branch also updates `refs/remotes/<remote>/<branch>`.
+        let fetched = Command::new("git")
+            .args(["fetch", "--quiet", remote, branch])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .output()
+            .is_ok_and(|out| out.status.success());
+        if fetched && let Some(remote) = tracking_ref(branch)? {
+            return Ok(Some(remote));
+        }
+    }
+    Ok(None)
+}
+
+fn tracking_ref(branch: &str) -> Result<Option<String>> {
+    let pattern = format!("refs/remotes/*/{branch}");
+    let out = git(&["for-each-ref", "--format=%(refname:short)", &pattern])?;
+    let refs = String::from_utf8_lossy(&out.stdout);
+    Ok(refs.lines().next().map(str::to_string))
+}
@@ -1,5 +1,6 @@
-use anyhow::{Result, bail};
-use std::io::IsTerminal;
+use anyhow::{Context, Result, bail};
+use std::io::{IsTerminal, Read};
+use std::path::Path;
 
 pub fn is_tty() -> bool {
     std::io::stdin().is_terminal()
@@ -26,3 +27,25 @@ pub fn select<T: std::fmt::Display>(prompt: &str, options: Vec<T>, flag: &str) -
     let result = inquire::Select::new(prompt, options).prompt()?;
     Ok(result)
 }
+
+/// Ask for confirmation; `--yes` skips the prompt and is required when not on a TTY.
+pub fn confirm(prompt: &str, yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if !is_tty() {
+        bail!("Refusing to continue without --yes (non-interactive mode)");
+    }
+    let result = inquire::Confirm::new(prompt).with_default(false).prompt()?;
+    Ok(result)
+}
+
+/// Read a `--*-file` argument; `-` reads stdin.
+pub fn read_file(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
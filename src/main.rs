@@ -2,12 +2,21 @@ mod api;
 mod cli;
 mod commands;
 mod config;
+mod editor;
+mod frontmatter;
+mod git;
 mod interactive;
+mod markdown;
 mod output;
+mod pager;
+mod template;
+mod theme;
 
 use anyhow::Result;
 use clap::Parser;
-use cli::{Cli, Commands, IssueCommands, TeamCommands};
+use colored::Colorize;
+use commands::issue::archive::Action;
+use cli::{Cli, CommentCommands, Commands, IssueCommands, StateType, TeamCommands};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -24,26 +33,168 @@ async fn main() -> Result<()> {
         Cli::parse()
     };
 
+    // A broken config.toml must not lock users out of `linear auth`.
+    let cfg = config::load().unwrap_or_else(|err| {
+        eprintln!("{} using default settings: {err:#}", "warning:".yellow().bold());
+        config::Config::default()
+    });
+    theme::init(cli.color, cfg.theme.as_ref());
+    pager::set_enabled(!cli.no_pager && cfg.pager.unwrap_or(true));
+
     match cli.command {
         Commands::Auth(args) => commands::auth::run(args).await,
         Commands::Me => commands::me::run().await,
         Commands::Api(args) => commands::api::run(args).await,
-        Commands::Issue { command } => match command {
+        Commands::Issue { command } => match *command {
             IssueCommands::List(args) => commands::issue::list::run(args).await,
-            IssueCommands::View { id, json } => commands::issue::view::run(id, json).await,
+            IssueCommands::View {
+                ids,
+                json,
+                raw,
+                comments,
+                template,
+            } => commands::issue::view::run(ids, json, raw, comments, template).await,
             IssueCommands::Create(args) => commands::issue::create::run(args).await,
+            IssueCommands::Edit {
+                id,
+                yes,
+                json,
+                template,
+            } => commands::issue::edit::run(id, yes, json, template).await,
             IssueCommands::Update(args) => commands::issue::update::run(args).await,
-            IssueCommands::Search { query, json } => {
-                commands::issue::search::run(query, json).await
+            IssueCommands::BulkUpdate(args) => commands::issue::bulk_update::run(args).await,
+            IssueCommands::Current { json, template } => {
+                commands::issue::current::run(json, template).await
+            }
+            IssueCommands::Search {
+                query,
+                json,
+                template,
+            } => commands::issue::search::run(query, json, template).await,
+            IssueCommands::Assign {
+                args,
+                unassign,
+                json,
+                template,
+            } => commands::issue::assign::run(args, unassign, json, template).await,
+            IssueCommands::State {
+                args,
+                state_type,
+                json,
+                template,
+            } => commands::issue::state::run(args, state_type, json, template).await,
+            IssueCommands::Start(args) => commands::issue::start::run(args).await,
+            IssueCommands::Done(args) => {
+                commands::issue::state::transition(args, StateType::Completed).await
+            }
+            IssueCommands::Cancel(args) => {
+                commands::issue::state::transition(args, StateType::Canceled).await
+            }
+            IssueCommands::Backlog(args) => {
+                commands::issue::state::transition(args, StateType::Backlog).await
+            }
+            IssueCommands::Triage(args) => {
+                commands::issue::state::transition(args, StateType::Triage).await
+            }
+            IssueCommands::Archive { target, yes } => {
+                commands::issue::archive::run(Action::Archive, target, yes).await
+            }
+            IssueCommands::Unarchive(target) => {
+                commands::issue::archive::run(Action::Unarchive, target, true).await
+            }
+            IssueCommands::Delete {
+                target,
+                yes,
+                permanent,
+            } => commands::issue::archive::run(Action::Delete { permanent }, target, yes).await,
+            IssueCommands::Restore(target) => {
+                commands::issue::archive::run(Action::Restore, target, true).await
             }
-            IssueCommands::Assign { id, user } => commands::issue::assign::run(id, user).await,
-            IssueCommands::State { id, state } => commands::issue::state::run(id, state).await,
-            IssueCommands::Comment { id, body, json } => {
-                commands::issue::comment::run(id, body, json).await
+            IssueCommands::Move { target, team } => {
+                commands::issue::transfer::move_issues(target, team).await
             }
+            IssueCommands::Copy {
+                target,
+                team,
+                with_comments,
+                with_sub_issues,
+            } => {
+                let options = commands::issue::transfer::CopyOptions {
+                    with_comments,
+                    with_sub_issues,
+                };
+                commands::issue::transfer::copy_issues(target, team, options).await
+            }
+            IssueCommands::Subscribe { target, user } => {
+                commands::issue::subscribe::run(target, user, true).await
+            }
+            IssueCommands::Unsubscribe { target, user } => {
+                commands::issue::subscribe::run(target, user, false).await
+            }
+            IssueCommands::Relate {
+                id,
+                relation,
+                other,
+                duplicate_of,
+                cancel,
+                json,
+            } => {
+                commands::issue::relate::run(id, relation, other, duplicate_of, cancel, json).await
+            }
+            IssueCommands::Unrelate {
+                id,
+                other,
+                relation,
+            } => commands::issue::unrelate::run(id, other, relation).await,
+            IssueCommands::Relations { id, json } => {
+                commands::issue::relations::run(id, json).await
+            }
+            IssueCommands::History { id, json } => commands::issue::history::run(id, json).await,
+            IssueCommands::Children { id, json } => {
+                commands::issue::children::run(id, json).await
+            }
+            IssueCommands::Tree { id, json } => commands::issue::tree::run(id, json).await,
+            IssueCommands::Reparent {
+                ids,
+                parent,
+                no_parent,
+                json,
+            } => commands::issue::reparent::run(ids, parent, no_parent, json).await,
+            IssueCommands::Comment {
+                ids,
+                body,
+                body_file,
+                json,
+                template,
+            } => commands::issue::comment::run(ids, body, body_file, json, template).await,
+        },
+        Commands::Comment { command } => match command {
+            CommentCommands::List {
+                issue,
+                json,
+                raw,
+                template,
+            } => commands::comment::list::run(issue, json, raw, template).await,
+            CommentCommands::Reply {
+                id,
+                body,
+                body_file,
+                json,
+                template,
+            } => commands::comment::reply::run(id, body, body_file, json, template).await,
+            CommentCommands::Edit {
+                id,
+                body,
+                body_file,
+                json,
+                template,
+            } => commands::comment::edit::run(id, body, body_file, json, template).await,
+            CommentCommands::Delete { id, yes } => commands::comment::delete::run(id, yes).await,
+            CommentCommands::Resolve { id } => commands::comment::resolve::run(id, true).await,
+            CommentCommands::Unresolve { id } => commands::comment::resolve::run(id, false).await,
         },
         Commands::Team { command } => match command {
-            TeamCommands::List => commands::team::list::run().await,
+            TeamCommands::List { template } => commands::team::list::run(template).await,
         },
     }
 }
@@ -0,0 +1,619 @@
+use colored::Colorize;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+use crate::output;
+use crate::theme;
+
+const CODE_THEME: &str = "base16-ocean.dark";
+const BULLET: &str = "•";
+const QUOTE_BAR: &str = "│ ";
+
+/// Render Markdown for the terminal, wrapped to `width` columns.
+pub fn render(text: &str, width: usize) -> Vec<String> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+    let mut renderer = Renderer::new(width.max(20));
+    for event in Parser::new_ext(text, options) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+enum Block {
+    Quote,
+    Item {
+        marker: String,
+        indent: usize,
+        marker_used: bool,
+    },
+}
+
+#[derive(Default)]
+struct Inline {
+    bold: usize,
+    italic: usize,
+    strike: usize,
+    heading: Option<HeadingLevel>,
+    link: Option<String>,
+}
+
+struct Table {
+    rows: Vec<Vec<String>>,
+    cell: String,
+}
+
+struct Renderer {
+    width: usize,
+    lines: Vec<String>,
+    line: String,
+    line_width: usize,
+    pending_space: bool,
+    blocks: Vec<Block>,
+    lists: Vec<Option<u64>>,
+    inline: Inline,
+    table: Option<Table>,
+    code_block: Option<(String, String)>,
+    hyperlinks: bool,
+}
+
+impl Renderer {
+    fn new(width: usize) -> Self {
+        Self {
+            width,
+            lines: Vec::new(),
+            line: String::new(),
+            line_width: 0,
+            pending_space: false,
+            blocks: Vec::new(),
+            lists: Vec::new(),
+            inline: Inline::default(),
+            table: None,
+            code_block: None,
+            hyperlinks: output::supports_hyperlinks(),
+        }
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        self.flush_line();
+        while self.lines.last().is_some_and(|l| is_blank(l)) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, code)) = &mut self.code_block {
+                    code.push_str(&text);
+                } else {
+                    self.push_text(&text);
+                }
+            }
+            Event::Code(code) => {
+                self.push_styled_word(&code.cyan().to_string());
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => self.push_text(&math),
+            // Tags are dropped so raw HTML never reaches the terminal; the text
+            // between them is kept.
+            Event::Html(html) | Event::InlineHtml(html) => {
+                if self.code_block.is_none() {
+                    let text = strip_tags(&html);
+                    if is_line_break(&html) {
+                        self.flush_line();
+                    } else if !text.trim().is_empty() {
+                        self.push_text(&text);
+                    }
+                }
+            }
+            Event::FootnoteReference(name) => {
+                let marker = format!("[^{name}]");
+                self.push_styled_word(&marker.dimmed().to_string());
+            }
+            Event::SoftBreak => self.pending_space = true,
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.flush_line();
+                self.blank_line();
+                let width = self.width.saturating_sub(self.prefix_width());
+                let rule = "─".repeat(width).dimmed().to_string();
+                self.emit(rule);
+                self.blank_line();
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(Block::Item {
+                    marker,
+                    marker_used,
+                    ..
+                }) = self.blocks.last_mut()
+                    && !*marker_used
+                {
+                    *marker = if checked {
+                        "☒".green().to_string()
+                    } else {
+                        "☐".to_string()
+                    };
+                }
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {}
+            Tag::Heading { level, .. } => {
+                self.flush_line();
+                self.blank_line();
+                self.inline.heading = Some(level);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush_line();
+                self.blocks.push(Block::Quote);
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush_line();
+                let lang = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split([',', ' '])
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code_block = Some((lang, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush_line();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush_line();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        let marker = format!("{n}.");
+                        *n += 1;
+                        marker
+                    }
+                    _ => BULLET.to_string(),
+                };
+                self.blocks.push(Block::Item {
+                    indent: output::display_width(&marker) + 1,
+                    marker,
+                    marker_used: false,
+                });
+            }
+            Tag::Emphasis => self.inline.italic += 1,
+            Tag::Strong => self.inline.bold += 1,
+            Tag::Strikethrough => self.inline.strike += 1,
+            Tag::Link { dest_url, .. } => self.inline.link = Some(dest_url.to_string()),
+            Tag::Image { dest_url, .. } => {
+                self.push_styled_word(&"[image]".dimmed().to_string());
+                self.inline.link = Some(dest_url.to_string());
+            }
+            Tag::Table(_) => {
+                self.flush_line();
+                self.table = Some(Table {
+                    rows: Vec::new(),
+                    cell: String::new(),
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(table) = &mut self.table {
+                    table.cell.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush_line();
+                if !self.in_list() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.flush_line();
+                self.inline.heading = None;
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_line();
+                if self.lines.last().is_some_and(|l| is_blank(l)) {
+                    self.lines.pop();
+                }
+                self.blocks.pop();
+                self.blank_line();
+            }
+            TagEnd::HtmlBlock => {
+                self.flush_line();
+                self.blank_line();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((lang, code)) = self.code_block.take() {
+                    self.emit_code_block(&lang, &code);
+                }
+                self.blank_line();
+            }
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Item => {
+                self.flush_line();
+                self.blocks.pop();
+            }
+            TagEnd::Emphasis => self.inline.italic = self.inline.italic.saturating_sub(1),
+            TagEnd::Strong => self.inline.bold = self.inline.bold.saturating_sub(1),
+            TagEnd::Strikethrough => self.inline.strike = self.inline.strike.saturating_sub(1),
+            TagEnd::Link | TagEnd::Image => {
+                if let Some(url) = self.inline.link.take()
+                    && !self.hyperlinks
+                    && self.table.is_none()
+                {
+                    let suffix = format!("({url})");
+                    self.pending_space = true;
+                    self.push_styled_word(&suffix.dimmed().to_string());
+                }
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = &mut self.table {
+                    let cell = std::mem::take(&mut table.cell);
+                    if let Some(row) = table.rows.last_mut() {
+                        row.push(cell);
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.emit_table(table);
+                }
+                self.blank_line();
+            }
+            _ => {}
+        }
+    }
+
+    fn in_list(&self) -> bool {
+        self.blocks.iter().any(|b| matches!(b, Block::Item { .. }))
+    }
+
+    fn prefix_width(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                Block::Quote => output::display_width(QUOTE_BAR),
+                Block::Item { indent, .. } => *indent,
+            })
+            .sum()
+    }
+
+    /// Build the line prefix; list markers are only shown on an item's first line.
+    fn take_prefix(&mut self) -> String {
+        let mut prefix = String::new();
+        for block in &mut self.blocks {
+            match block {
+                Block::Quote => prefix.push_str(&QUOTE_BAR.dimmed().to_string()),
+                Block::Item {
+                    marker,
+                    indent,
+                    marker_used,
+                } => {
+                    if *marker_used {
+                        prefix.push_str(&" ".repeat(*indent));
+                    } else {
+                        prefix.push_str(marker);
+                        prefix.push(' ');
+                        *marker_used = true;
+                    }
+                }
+            }
+        }
+        prefix
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.starts_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+        for (i, word) in text.split_whitespace().enumerate() {
+            if i > 0 {
+                self.pending_space = true;
+            }
+            let styled = self.style(word);
+            self.push_styled_word(&styled);
+        }
+        if text.ends_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+    }
+
+    fn push_styled_word(&mut self, styled: &str) {
+        if let Some(table) = &mut self.table {
+            if self.pending_space && !table.cell.is_empty() {
+                table.cell.push(' ');
+            }
+            table.cell.push_str(styled);
+            self.pending_space = false;
+            return;
+        }
+
+        let width = output::display_width(styled);
+        let available = self.width.saturating_sub(self.prefix_width());
+        let space = usize::from(self.pending_space && self.line_width > 0);
+        if self.line_width > 0 && self.line_width + space + width > available {
+            self.flush_line();
+        }
+        if self.pending_space && self.line_width > 0 {
+            self.line.push(' ');
+            self.line_width += 1;
+        }
+        self.line.push_str(styled);
+        self.line_width += width;
+        self.pending_space = false;
+    }
+
+    fn style(&self, word: &str) -> String {
+        let mut styled = word.normal();
+        match self.inline.heading {
+            Some(HeadingLevel::H1) => styled = styled.bold().underline(),
+            Some(HeadingLevel::H2) => styled = styled.bold(),
+            Some(_) => styled = styled.bold().dimmed(),
+            None => {}
+        }
+        if self.inline.bold > 0 {
+            styled = styled.bold();
+        }
+        if self.inline.italic > 0 {
+            styled = styled.italic();
+        }
+        if self.inline.strike > 0 {
+            styled = styled.strikethrough();
+        }
+        match &self.inline.link {
+            Some(url) => {
+                let styled = styled.blue().underline().to_string();
+                if self.hyperlinks {
+                    output::hyperlink(&styled, url)
+                } else {
+                    styled
+                }
+            }
+            None => styled.to_string(),
+        }
+    }
+
+    fn flush_line(&mut self) {
+        if self.line.is_empty() {
+            self.pending_space = false;
+            return;
+        }
+        let line = std::mem::take(&mut self.line);
+        let prefix = self.take_prefix();
+        self.lines.push(format!("{prefix}{line}"));
+        self.line_width = 0;
+        self.pending_space = false;
+    }
+
+    fn emit(&mut self, content: String) {
+        let prefix = self.take_prefix();
+        self.lines.push(format!("{prefix}{content}"));
+    }
+
+    fn blank_line(&mut self) {
+        if self.lines.last().is_none_or(|l| is_blank(l)) {
+            return;
+        }
+        if self.blocks.iter().any(|b| matches!(b, Block::Quote)) {
+            let bars = self
+                .blocks
+                .iter()
+                .filter(|b| matches!(b, Block::Quote))
+                .map(|_| QUOTE_BAR.trim_end())
+                .collect::<String>();
+            self.lines.push(bars.dimmed().to_string());
+        } else {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn emit_code_block(&mut self, lang: &str, code: &str) {
+        self.blank_line();
+        for line in highlight(lang, code) {
+            self.emit(format!("  {line}"));
+        }
+    }
+
+    fn emit_table(&mut self, table: Table) {
+        let mut builder = Builder::new();
+        for row in table.rows {
+            builder.push_record(row);
+        }
+        let mut rendered = builder.build();
+        rendered.with(Style::rounded());
+        self.blank_line();
+        for line in rendered.to_string().lines() {
+            self.emit(line.to_string());
+        }
+    }
+}
+
+/// Blank lines may still carry a dimmed quote bar.
+fn is_blank(line: &str) -> bool {
+    line.replace(&QUOTE_BAR.trim_end().dimmed().to_string(), "")
+        .trim()
+        .is_empty()
+}
+
+/// `html` without its tags and comments, e.g. `<b>bold</b>` → `bold`.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let end = if rest[start..].starts_with("<!--") {
+            rest[start..].find("-->").map(|i| start + i + 3)
+        } else {
+            rest[start..].find('>').map(|i| start + i + 1)
+        };
+        let Some(end) = end else {
+            // A lone `<` is text.
+            text.push_str(&rest[start..]);
+            return text;
+        };
+        rest = &rest[end..];
+    }
+    text.push_str(rest);
+    text
+}
+
+fn is_line_break(html: &str) -> bool {
+    let tag = html.trim().to_ascii_lowercase();
+    matches!(tag.as_str(), "<br>" | "<br/>" | "<br />")
+}
+
+fn syntaxes() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn code_theme() -> &'static Theme {
+    static THEME: OnceLock<Theme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        let mut themes = ThemeSet::load_defaults().themes;
+        themes.remove(CODE_THEME).unwrap_or_default()
+    })
+}
+
+fn highlight(lang: &str, code: &str) -> Vec<String> {
+    let plain = || code.lines().map(|l| l.dimmed().to_string()).collect();
+    if lang.is_empty() || !theme::enabled() {
+        return plain();
+    }
+    let syntaxes = syntaxes();
+    let Some(syntax) = syntaxes.find_syntax_by_token(lang) else {
+        return plain();
+    };
+
+    let mut highlighter = HighlightLines::new(syntax, code_theme());
+    let mut lines = Vec::new();
+    for line in LinesWithEndings::from(code) {
+        let Ok(ranges) = highlighter.highlight_line(line, syntaxes) else {
+            return plain();
+        };
+        let mut escaped = String::new();
+        for (style, text) in ranges {
+            let fg = style.foreground;
+            let text = text.trim_end_matches(['\n', '\r']);
+            escaped.push_str(&format!("\x1b[{}m{text}", theme::rgb_fg(fg.r, fg.g, fg.b)));
+        }
+        lines.push(format!("{escaped}\x1b[0m"));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rendered lines with SGR escapes removed.
+    fn plain(text: &str, width: usize) -> Vec<String> {
+        render(text, width)
+            .iter()
+            .map(|line| {
+                let mut out = String::new();
+                let mut chars = line.chars();
+                while let Some(c) = chars.next() {
+                    if c == '\x1b' {
+                        chars.by_ref().find(|c| c.is_ascii_alphabetic());
+                    } else {
+                        out.push(c);
+                    }
+                }
+                out
+            })
+            .collect()
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            plain("# Title\n\nBody text", 40),
+            ["Title", "", "Body text"]
+        );
+        assert_eq!(plain("Intro\n\n### Small", 40), ["Intro", "", "Small"]);
+    }
+
+    #[test]
+    fn lists_wrap_under_their_marker() {
+        assert_eq!(
+            plain("- one\n- two words that wrap\n\n1. first\n2. second", 20),
+            [
+                "• one",
+                "• two words that",
+                "  wrap",
+                "",
+                "1. first",
+                "2. second"
+            ]
+        );
+        assert_eq!(plain("- [x] done\n- [ ] todo", 40), ["☒ done", "☐ todo"]);
+    }
+
+    #[test]
+    fn code_blocks_keep_their_lines() {
+        let text = "```\nfn main() {\n    let x = 1;\n}\n```";
+        assert_eq!(
+            plain(text, 40),
+            ["  fn main() {", "      let x = 1;", "  }"]
+        );
+    }
+
+    #[test]
+    fn links_show_their_url() {
+        assert_eq!(
+            plain("See [the docs](https://linear.app/docs).", 80),
+            ["See the docs (https://linear.app/docs)."]
+        );
+    }
+
+    #[test]
+    fn html_tags_are_dropped() {
+        assert_eq!(
+            plain("Some <b>bold</b> text<br>next", 40),
+            ["Some bold text", "next"]
+        );
+        assert_eq!(
+            plain(
+                "<details>\n<summary>More</summary>\n\nHidden\n</details>",
+                40
+            ),
+            ["More", "", "Hidden"]
+        );
+        assert_eq!(plain("a <!-- note --> b", 40), ["a b"]);
+    }
+}
@@ -1,11 +1,16 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, Utc};
 use colored::Colorize;
 use crossterm::terminal;
 use std::io::IsTerminal;
 use tabled::builder::Builder;
 use tabled::settings::Style;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use crate::api::types::{Issue, Team};
+use crate::api::types::{Comment, Cycle, Issue, IssueHistory, Project, Team, User, WorkflowState};
+use crate::markdown;
+use crate::pager;
+use crate::theme;
 
 const MIN_TITLE_WIDTH: usize = 16;
 const PREFERRED_TITLE_WIDTH: usize = 28;
@@ -15,6 +20,7 @@ const MAX_STATE_WIDTH: usize = 20;
 const MAX_ASSIGNEE_WIDTH: usize = 24;
 const TABLE_OVERHEAD: usize = 18;
 const PRIORITY_WIDTH: usize = 8;
+const MAX_THREAD_DEPTH: usize = 4;
 
 struct IssueTableWidths {
     id: usize,
@@ -33,16 +39,6 @@ pub fn priority_label(p: i32) -> &'static str {
     }
 }
 
-fn state_colored(name: &str, state_type: &str) -> String {
-    match state_type {
-        "completed" => name.green().to_string(),
-        "canceled" | "cancelled" => name.red().strikethrough().to_string(),
-        "started" => name.yellow().to_string(),
-        "unstarted" => name.dimmed().to_string(),
-        _ => name.to_string(),
-    }
-}
-
 fn terminal_width() -> usize {
     terminal::size()
         .map(|(w, _)| w as usize)
@@ -55,34 +51,128 @@ fn terminal_width() -> usize {
         .unwrap_or(120)
 }
 
-fn truncate_visible(text: &str, max_chars: usize) -> String {
-    if max_chars == 0 {
+/// Terminal columns taken by `text`, ignoring ANSI escapes and counting wide
+/// (CJK, emoji) graphemes as two columns and combining marks as zero.
+pub fn display_width(text: &str) -> usize {
+    segments(text)
+        .filter_map(|segment| match segment {
+            Segment::Visible(grapheme) => Some(grapheme.width()),
+            Segment::Escape(_) => None,
+        })
+        .sum()
+}
+
+/// Truncate `text` to at most `max_width` columns, ending with `…` when cut.
+/// ANSI escapes are preserved; a hyperlink left open is closed and styles are
+/// reset after the ellipsis.
+pub fn truncate_visible(text: &str, max_width: usize) -> String {
+    if max_width == 0 {
         return String::new();
     }
-    if text.chars().count() <= max_chars {
+    if display_width(text) <= max_width {
         return text.to_string();
     }
-    if max_chars == 1 {
-        return "…".to_string();
+
+    let mut out = String::new();
+    let mut used = 0;
+    let mut has_escapes = false;
+    let mut link_open = false;
+    for segment in segments(text) {
+        match segment {
+            Segment::Escape(escape) => {
+                has_escapes = true;
+                if let Some(link) = escape.strip_prefix("\x1b]8;") {
+                    // `ESC ] 8 ; params ; URI ST`; an empty URI ends the link.
+                    let uri = link.split_once(';').map_or("", |(_, rest)| rest);
+                    link_open = !uri.trim_end_matches(['\x07', '\x1b', '\\']).is_empty();
+                }
+                out.push_str(escape);
+            }
+            Segment::Visible(grapheme) => {
+                let width = grapheme.width();
+                if used + width > max_width - 1 {
+                    break;
+                }
+                out.push_str(grapheme);
+                used += width;
+            }
+        }
+    }
+    out.push('…');
+    if link_open {
+        out.push_str("\x1b]8;;\x1b\\");
+    }
+    if has_escapes {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+/// Left-align `text` in a column of `width` terminal columns.
+pub fn pad_visible(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
+enum Segment<'a> {
+    Visible(&'a str),
+    Escape(&'a str),
+}
+
+/// Split `text` into graphemes and ANSI escape sequences (CSI and OSC, as used
+/// for colors and hyperlinks).
+fn segments(text: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let len = escape_len(rest)
+            .or_else(|| rest.graphemes(true).next().map(str::len))
+            .unwrap_or(rest.len());
+        let (head, tail) = rest.split_at(len);
+        rest = tail;
+        if head.starts_with('\x1b') {
+            Some(Segment::Escape(head))
+        } else {
+            Some(Segment::Visible(head))
+        }
+    })
+}
+
+fn escape_len(text: &str) -> Option<usize> {
+    let body = text.strip_prefix('\x1b')?;
+    if let Some(csi) = body.strip_prefix('[') {
+        let end = csi.find(|c: char| ('@'..='~').contains(&c))?;
+        return Some(2 + end + 1);
+    }
+    if let Some(osc) = body.strip_prefix(']') {
+        let bel = osc.find('\x07').map(|i| (i, 1));
+        let st = osc.find("\x1b\\").map(|i| (i, 2));
+        let (end, terminator) = match (bel, st) {
+            (Some(b), Some(s)) => b.min(s),
+            (b, s) => b.or(s)?,
+        };
+        return Some(2 + end + terminator);
     }
-    format!("{}…", text.chars().take(max_chars - 1).collect::<String>())
+    Some(1)
 }
 
-fn supports_hyperlinks() -> bool {
-    if !std::io::stdout().is_terminal() {
+pub fn supports_hyperlinks() -> bool {
+    if !std::io::stdout().is_terminal() || !theme::enabled() {
         return false;
     }
     !matches!(std::env::var("TERM").ok().as_deref(), Some("dumb"))
 }
 
-fn hyperlink(label: &str, url: &str) -> String {
+pub fn hyperlink(label: &str, url: &str) -> String {
     format!("\x1b]8;;{url}\x1b\\{label}\x1b]8;;\x1b\\")
 }
 
 fn compute_issue_table_widths(issues: &[Issue]) -> IssueTableWidths {
     let id_width = issues
         .iter()
-        .map(|issue| issue.identifier.as_deref().unwrap_or("—").chars().count())
+        .map(|issue| display_width(issue.identifier.as_deref().unwrap_or("—")))
         .max()
         .unwrap_or(2)
         .clamp(2, MAX_ID_WIDTH);
@@ -90,13 +180,13 @@ fn compute_issue_table_widths(issues: &[Issue]) -> IssueTableWidths {
     let state_width = issues
         .iter()
         .map(|issue| {
-            issue
-                .state
-                .as_ref()
-                .and_then(|state| state.name.as_deref())
-                .unwrap_or("—")
-                .chars()
-                .count()
+            display_width(
+                issue
+                    .state
+                    .as_ref()
+                    .and_then(|state| state.name.as_deref())
+                    .unwrap_or("—"),
+            )
         })
         .max()
         .unwrap_or(5)
@@ -105,13 +195,13 @@ fn compute_issue_table_widths(issues: &[Issue]) -> IssueTableWidths {
     let assignee_width = issues
         .iter()
         .map(|issue| {
-            issue
-                .assignee
-                .as_ref()
-                .and_then(|user| user.display_name.as_deref().or(user.name.as_deref()))
-                .unwrap_or("Unassigned")
-                .chars()
-                .count()
+            display_width(
+                issue
+                    .assignee
+                    .as_ref()
+                    .and_then(|user| user.display_name.as_deref().or(user.name.as_deref()))
+                    .unwrap_or("Unassigned"),
+            )
         })
         .max()
         .unwrap_or(10)
@@ -166,10 +256,10 @@ pub fn issue_table(issues: &[Issue]) {
                 let name = s.name.as_deref().unwrap_or("—");
                 let name = truncate_visible(name, widths.state);
                 let st = s.state_type.as_deref().unwrap_or("");
-                state_colored(&name, st)
+                theme::state(&name, st)
             })
             .unwrap_or_else(|| "—".to_string());
-        let priority = priority_label(issue.priority.unwrap_or(0));
+        let priority = theme::priority(priority_label(issue.priority.unwrap_or(0)));
         let assignee = issue
             .assignee
             .as_ref()
@@ -177,15 +267,32 @@ pub fn issue_table(issues: &[Issue]) {
             .unwrap_or("Unassigned");
         let assignee = truncate_visible(assignee, widths.assignee);
 
-        builder.push_record([&id, &title, &state, priority, &assignee]);
+        builder.push_record([&id, &title, &state, &priority, &assignee]);
     }
 
     let mut table = builder.build();
     table.with(Style::rounded());
-    println!("{table}");
+    pager::page(&table.to_string());
+}
+
+/// The planned changes per issue, as a table for the pager or stderr.
+pub fn change_table(rows: &[(&Issue, Vec<String>)]) -> String {
+    let mut builder = Builder::new();
+    builder.push_record(["ID", "Title", "Changes"]);
+    let title_width = terminal_width()
+        .saturating_sub(60)
+        .clamp(MIN_TITLE_WIDTH, 60);
+    for (issue, changes) in rows {
+        let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+        let title = truncate_visible(issue.title.as_deref().unwrap_or("—"), title_width);
+        builder.push_record([id.to_string(), title, changes.join("\n")]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    table.to_string()
 }
 
-fn relative_time(iso: &str) -> String {
+pub fn relative_time(iso: &str) -> String {
     let Ok(dt) = iso.parse::<DateTime<Utc>>() else {
         return iso.to_string();
     };
@@ -222,11 +329,243 @@ fn relative_time(iso: &str) -> String {
 
 fn section_rule(label: &str, width: usize) -> String {
     let prefix = format!("── {label} ");
-    let remaining = width.saturating_sub(prefix.len());
-    format!("{}{}", prefix, "─".repeat(remaining)).dimmed().to_string()
+    let remaining = width.saturating_sub(display_width(&prefix));
+    format!("{}{}", prefix, "─".repeat(remaining))
+        .dimmed()
+        .to_string()
+}
+
+fn user_name(user: &User) -> &str {
+    user.display_name
+        .as_deref()
+        .or(user.name.as_deref())
+        .unwrap_or("Unknown")
+}
+
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 {
+        format!("{}", n as i64)
+    } else {
+        n.to_string()
+    }
+}
+
+fn is_done(issue: &Issue) -> bool {
+    issue
+        .state
+        .as_ref()
+        .and_then(|s| s.state_type.as_deref())
+        .is_some_and(|t| matches!(t, "completed" | "canceled" | "cancelled"))
+}
+
+fn link_or_plain(label: &str, url: Option<&str>) -> String {
+    match url {
+        Some(url) if supports_hyperlinks() => hyperlink(label, url),
+        _ => label.to_string(),
+    }
+}
+
+/// One-line reference to a related issue: hyperlinked identifier, title and state.
+fn issue_ref(issue: &Issue) -> String {
+    let id = issue.identifier.as_deref().unwrap_or(&issue.id);
+    let id = link_or_plain(id, issue.url.as_deref());
+    let title = issue.title.as_deref().unwrap_or("—");
+    match &issue.state {
+        Some(state) => {
+            let name = state.name.as_deref().unwrap_or("—");
+            let st = state.state_type.as_deref().unwrap_or("");
+            format!("{id}  {title}  [{}]", theme::state(name, st))
+        }
+        None => format!("{id}  {title}"),
+    }
+}
+
+/// Outbound and inbound relations, labeled from this issue's point of view.
+fn relation_lines(issue: &Issue) -> Vec<(&'static str, &Issue)> {
+    let mut lines = Vec::new();
+    for relation in issue.relations.iter().flat_map(|r| &r.nodes) {
+        let kind = match relation.relation_type.as_deref() {
+            Some("blocks") => "Blocks",
+            Some("duplicate") => "Duplicate of",
+            Some("similar") => "Similar to",
+            _ => "Related to",
+        };
+        if let Some(related) = relation.related_issue.as_deref() {
+            lines.push((kind, related));
+        }
+    }
+    for relation in issue.inverse_relations.iter().flat_map(|r| &r.nodes) {
+        let kind = match relation.relation_type.as_deref() {
+            Some("blocks") => "Blocked by",
+            Some("duplicate") => "Duplicated by",
+            Some("similar") => "Similar to",
+            _ => "Related to",
+        };
+        if let Some(related) = relation.issue.as_deref() {
+            lines.push((kind, related));
+        }
+    }
+    lines
+}
+
+/// One line per relation, for `linear issue relations`.
+pub fn relation_list(issue: &Issue) {
+    let relations = relation_lines(issue);
+    if relations.is_empty() {
+        println!("No relations.");
+        return;
+    }
+    for (kind, related) in relations {
+        println!(
+            "{} {}",
+            pad_visible(&kind.dimmed().to_string(), 14),
+            issue_ref(related)
+        );
+    }
+}
+
+/// Sub-issue hierarchy as an indented tree, with done/total counts on parents.
+pub fn issue_tree(root: &Issue) {
+    let mut out = vec![tree_line(root)];
+    push_subtree(&mut out, root, "");
+    pager::page(&out.join("\n"));
+}
+
+fn push_subtree(out: &mut Vec<String>, issue: &Issue, prefix: &str) {
+    let children = issue
+        .children
+        .as_ref()
+        .map(|c| c.nodes.as_slice())
+        .unwrap_or_default();
+    for (i, child) in children.iter().enumerate() {
+        let (branch, indent) = if i + 1 == children.len() {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        out.push(format!(
+            "{}{}",
+            format!("{prefix}{branch}").dimmed(),
+            tree_line(child)
+        ));
+        push_subtree(out, child, &format!("{prefix}{indent}"));
+    }
+}
+
+fn tree_line(issue: &Issue) -> String {
+    match progress(issue) {
+        Some((done, total)) => format!(
+            "{}  {}",
+            issue_ref(issue),
+            format!("{done}/{total} done").dimmed()
+        ),
+        None => issue_ref(issue),
+    }
+}
+
+/// Done and total counts over all descendants; `None` for leaves.
+fn progress(issue: &Issue) -> Option<(usize, usize)> {
+    let children = issue.children.as_ref().filter(|c| !c.nodes.is_empty())?;
+    let (mut done, mut total) = (0, 0);
+    for child in &children.nodes {
+        total += 1;
+        if is_done(child) {
+            done += 1;
+        }
+        if let Some((d, t)) = progress(child) {
+            done += d;
+            total += t;
+        }
+    }
+    Some((done, total))
+}
+
+fn parent_id(comment: &Comment) -> Option<&str> {
+    comment.parent.as_ref().map(|p| p.id.as_str())
+}
+
+/// Render a comment and its replies, indenting each reply level.
+fn push_comment_thread(
+    out: &mut Vec<String>,
+    comment: &Comment,
+    all: &[Comment],
+    depth: usize,
+    width: usize,
+    raw: bool,
+    show_ids: bool,
+) {
+    let indent = " ".repeat(2 + 4 * depth.min(MAX_THREAD_DEPTH));
+    let author = comment.user.as_ref().map(user_name).unwrap_or("Unknown");
+    let time = comment
+        .created_at
+        .as_deref()
+        .map(relative_time)
+        .unwrap_or_default();
+
+    let mut header = format!("{indent}{}  {}", author.bold(), time.dimmed());
+    if show_ids {
+        header.push_str(&format!("  {}", comment.id.dimmed()));
+    }
+    if comment.edited_at.is_some() {
+        header.push_str(&format!("  {}", "(edited)".dimmed()));
+    }
+    if comment.resolved_at.is_some() {
+        let resolver = comment
+            .resolving_user
+            .as_ref()
+            .map(|u| format!(" by {}", user_name(u)))
+            .unwrap_or_default();
+        header.push_str(&format!("  {}", format!("✓ resolved{resolver}").green()));
+    }
+
+    out.push(String::new());
+    out.push(header);
+    if let Some(body) = &comment.body {
+        let body_width = width.saturating_sub(indent.len() - 2);
+        for line in body_lines(body, body_width, raw) {
+            out.push(format!("{indent}{line}"));
+        }
+    }
+
+    for reply in all
+        .iter()
+        .filter(|c| parent_id(c) == Some(comment.id.as_str()))
+    {
+        push_comment_thread(out, reply, all, depth + 1, width, raw, show_ids);
+    }
 }
 
-pub fn issue_detail(issue: &Issue) {
+/// Render top-level comments (and replies whose parent is missing) as threads.
+fn push_comments(
+    out: &mut Vec<String>,
+    comments: &[Comment],
+    width: usize,
+    raw: bool,
+    show_ids: bool,
+) {
+    let ids: Vec<&str> = comments.iter().map(|c| c.id.as_str()).collect();
+    let roots = comments
+        .iter()
+        .filter(|c| parent_id(c).is_none_or(|p| !ids.contains(&p)));
+    for comment in roots {
+        push_comment_thread(out, comment, comments, 0, width, raw, show_ids);
+    }
+}
+
+fn field_label(label: &str) -> String {
+    pad_visible(&label.dimmed().to_string(), 10)
+}
+
+/// Lines of a Markdown body, rendered for the terminal unless `raw` is set.
+fn body_lines(body: &str, width: usize, raw: bool) -> Vec<String> {
+    if raw {
+        body.lines().map(str::to_string).collect()
+    } else {
+        markdown::render(body, width.saturating_sub(2))
+    }
+}
+
+pub fn issue_detail(issue: &Issue, raw: bool) {
     let width = terminal_width().min(80);
     let id = issue.identifier.as_deref().unwrap_or("—");
     let title = issue.title.as_deref().unwrap_or("—");
@@ -236,10 +575,10 @@ pub fn issue_detail(issue: &Issue) {
         .map(|s| {
             let name = s.name.as_deref().unwrap_or("—");
             let st = s.state_type.as_deref().unwrap_or("");
-            state_colored(name, st)
+            theme::state(name, st)
         })
         .unwrap_or_else(|| "—".to_string());
-    let priority = priority_label(issue.priority.unwrap_or(0));
+    let priority = theme::priority(priority_label(issue.priority.unwrap_or(0)));
     let team = issue
         .team
         .as_ref()
@@ -256,92 +595,325 @@ pub fn issue_detail(issue: &Issue) {
         .map(|l| {
             l.nodes
                 .iter()
-                .filter_map(|l| l.name.as_deref())
+                .filter_map(|l| {
+                    l.name
+                        .as_deref()
+                        .map(|name| theme::label(name, l.color.as_deref()))
+                })
                 .collect::<Vec<_>>()
                 .join(", ")
         })
         .unwrap_or_default();
-    let project = issue
-        .project
-        .as_ref()
-        .and_then(|p| p.name.as_deref());
+    let project = issue.project.as_ref().and_then(|p| p.name.as_deref());
     let url = issue.url.as_deref().unwrap_or("—");
+    let mut out = Vec::new();
 
     // Header
-    println!("{}", format!("{id}  {title}").bold());
-    println!();
+    let mut header = format!("{id}  {title}").bold().to_string();
+    if issue.trashed == Some(true) {
+        header.push_str(&format!("  {}", "[in trash]".red()));
+    } else if issue.archived_at.is_some() {
+        header.push_str(&format!("  {}", "[archived]".dimmed()));
+    }
+    out.push(header);
+    out.push(String::new());
 
     // Two-column metadata
-    println!(
-        "  {:<10} {:<26} {:<10} {}",
-        "State:".dimmed(),
-        state,
-        "Priority:".dimmed(),
+    out.push(format!(
+        "  {} {} {} {}",
+        field_label("State:"),
+        pad_visible(&state, 26),
+        field_label("Priority:"),
         priority
-    );
-    println!(
-        "  {:<10} {:<26} {:<10} {}",
-        "Team:".dimmed(),
-        team,
-        "Assignee:".dimmed(),
+    ));
+    out.push(format!(
+        "  {} {} {} {}",
+        field_label("Team:"),
+        pad_visible(team, 26),
+        field_label("Assignee:"),
         assignee
-    );
+    ));
     if !labels.is_empty() {
-        println!("  {:<10} {}", "Labels:".dimmed(), labels);
+        out.push(format!("  {} {}", field_label("Labels:"), labels));
     }
     if let Some(proj) = project {
-        println!("  {:<10} {}", "Project:".dimmed(), proj);
+        let milestone = issue
+            .project_milestone
+            .as_ref()
+            .and_then(|m| m.name.as_deref())
+            .map(|name| format!(" · {name}"))
+            .unwrap_or_default();
+        out.push(format!("  {} {proj}{milestone}", field_label("Project:")));
+    }
+    if let Some(cycle) = &issue.cycle {
+        let number = cycle.number.map(format_number).unwrap_or_default();
+        let label = match cycle.name.as_deref() {
+            Some(name) if !name.is_empty() => format!("{number} — {name}"),
+            _ => number,
+        };
+        out.push(format!("  {} {}", field_label("Cycle:"), label));
+    }
+    if let Some(due) = &issue.due_date {
+        out.push(format!("  {} {}", field_label("Due:"), due));
+    }
+    if let Some(estimate) = issue.estimate {
+        out.push(format!(
+            "  {} {}",
+            field_label("Estimate:"),
+            format_number(estimate)
+        ));
+    }
+    if let Some(creator) = &issue.creator {
+        out.push(format!(
+            "  {} {}",
+            field_label("Creator:"),
+            user_name(creator)
+        ));
+    }
+    if let Some(parent) = &issue.parent {
+        out.push(format!(
+            "  {} {}",
+            field_label("Parent:"),
+            issue_ref(parent)
+        ));
+    }
+    if let Some(subscribers) = &issue.subscribers {
+        if !subscribers.nodes.is_empty() {
+            let names = subscribers
+                .nodes
+                .iter()
+                .map(user_name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push(format!("  {} {}", field_label("Watching:"), names));
+        }
     }
-    println!("  {:<10} {}", "URL:".dimmed(), url);
+    out.push(format!("  {} {}", field_label("URL:"), url));
 
     // Description
     if let Some(desc) = &issue.description {
         if !desc.is_empty() {
-            println!();
-            println!("{}", section_rule("Description", width));
-            println!();
-            for line in desc.lines() {
-                println!("  {line}");
+            out.push(String::new());
+            out.push(section_rule("Description", width));
+            out.push(String::new());
+            for line in body_lines(desc, width, raw) {
+                out.push(format!("  {line}"));
             }
         }
     }
 
-    // Comments
-    if let Some(comments) = &issue.comments {
-        if !comments.nodes.is_empty() {
-            let count = comments.nodes.len();
-            println!();
-            println!(
-                "{}",
-                section_rule(&format!("Comments ({count})"), width)
-            );
-
-            for comment in &comments.nodes {
-                let author = comment
-                    .user
-                    .as_ref()
-                    .and_then(|u| u.display_name.as_deref().or(u.name.as_deref()))
-                    .unwrap_or("Unknown");
-                let time = comment
-                    .created_at
+    // Sub-issues
+    if let Some(children) = &issue.children {
+        if !children.nodes.is_empty() {
+            let done = children.nodes.iter().filter(|c| is_done(c)).count();
+            let total = children.nodes.len();
+            out.push(String::new());
+            // Only direct children are fetched here; `issue tree` counts all descendants.
+            out.push(section_rule(
+                &format!("Direct sub-issues ({done}/{total} done)"),
+                width,
+            ));
+            out.push(String::new());
+            for child in &children.nodes {
+                out.push(format!("  {}", issue_ref(child)));
+            }
+        }
+    }
+
+    // Relations
+    let relations = relation_lines(issue);
+    if !relations.is_empty() {
+        out.push(String::new());
+        out.push(section_rule("Relations", width));
+        out.push(String::new());
+        for (kind, related) in relations {
+            out.push(format!(
+                "  {} {}",
+                pad_visible(&kind.dimmed().to_string(), 14),
+                issue_ref(related)
+            ));
+        }
+    }
+
+    // Attachments
+    if let Some(attachments) = &issue.attachments {
+        if !attachments.nodes.is_empty() {
+            out.push(String::new());
+            out.push(section_rule(
+                &format!("Attachments ({})", attachments.nodes.len()),
+                width,
+            ));
+            out.push(String::new());
+            for attachment in &attachments.nodes {
+                let title = attachment
+                    .title
                     .as_deref()
-                    .map(relative_time)
-                    .unwrap_or_default();
-
-                println!();
-                println!(
-                    "  {}  {}",
-                    author.bold(),
-                    time.dimmed()
-                );
-                if let Some(body) = &comment.body {
-                    for line in body.lines() {
-                        println!("  {line}");
+                    .or(attachment.url.as_deref())
+                    .unwrap_or("—");
+                let title = link_or_plain(title, attachment.url.as_deref());
+                match attachment.subtitle.as_deref() {
+                    Some(subtitle) if !subtitle.is_empty() => {
+                        out.push(format!("  {title}  {}", subtitle.dimmed()))
                     }
+                    _ => out.push(format!("  {title}")),
                 }
             }
         }
     }
+
+    // Comments
+    if let Some(comments) = &issue.comments {
+        if !comments.nodes.is_empty() {
+            let count = comments.nodes.len();
+            out.push(String::new());
+            out.push(section_rule(&format!("Comments ({count})"), width));
+            push_comments(&mut out, &comments.nodes, width, raw, false);
+        }
+    }
+
+    pager::page(&out.join("\n"));
+}
+
+/// Comments of an issue with their IDs, so they can be passed to `linear comment`.
+pub fn comment_list(comments: &[Comment], raw: bool) {
+    if comments.is_empty() {
+        println!("No comments.");
+        return;
+    }
+    let width = terminal_width().min(80);
+    let mut out = Vec::new();
+    push_comments(&mut out, comments, width, raw, true);
+    // Drop the blank line that separates threads.
+    out.remove(0);
+    pager::page(&out.join("\n"));
+}
+
+/// An issue's change log as a timeline: a header per entry with one line per change.
+pub fn history_timeline(entries: &[IssueHistory]) {
+    let mut out = Vec::new();
+    for entry in entries {
+        let changes = history_changes(entry);
+        if changes.is_empty() {
+            continue;
+        }
+        let actor = entry.actor.as_ref().map(user_name).unwrap_or("Linear");
+        let time = entry
+            .created_at
+            .as_deref()
+            .map(timestamp)
+            .unwrap_or_default();
+        if !out.is_empty() {
+            out.push(String::new());
+        }
+        out.push(format!("{}  {}", time.dimmed(), actor.bold()));
+        for (label, change) in changes {
+            out.push(format!("  {} {change}", field_label(label)));
+        }
+    }
+    if out.is_empty() {
+        println!("No history.");
+        return;
+    }
+    pager::page(&out.join("\n"));
+}
+
+/// Local date and time followed by the relative time, e.g. `2024-05-02 14:03 (3d ago)`.
+fn timestamp(iso: &str) -> String {
+    match iso.parse::<DateTime<Utc>>() {
+        Ok(dt) => format!(
+            "{} ({})",
+            dt.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            relative_time(iso)
+        ),
+        Err(_) => iso.to_string(),
+    }
+}
+
+fn history_changes(entry: &IssueHistory) -> Vec<(&'static str, String)> {
+    let transition = |from: Option<String>, to: Option<String>| {
+        let shown = |value: Option<String>| {
+            value
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| "(none)".dimmed().to_string())
+        };
+        format!("{} → {}", shown(from), shown(to))
+    };
+    let mut changes = Vec::new();
+
+    if entry.from_title.is_some() || entry.to_title.is_some() {
+        let change = transition(entry.from_title.clone(), entry.to_title.clone());
+        changes.push(("Title:", change));
+    }
+    if entry.from_state.is_some() || entry.to_state.is_some() {
+        let state = |state: &Option<WorkflowState>| {
+            state.as_ref().map(|s| {
+                let name = s.name.as_deref().unwrap_or("—");
+                theme::state(name, s.state_type.as_deref().unwrap_or(""))
+            })
+        };
+        let change = transition(state(&entry.from_state), state(&entry.to_state));
+        changes.push(("State:", change));
+    }
+    if entry.from_assignee.is_some() || entry.to_assignee.is_some() {
+        let user = |user: &Option<User>| user.as_ref().map(|u| user_name(u).to_string());
+        let change = transition(user(&entry.from_assignee), user(&entry.to_assignee));
+        changes.push(("Assignee:", change));
+    }
+    let added = entry.added_labels.iter().flatten();
+    let removed = entry.removed_labels.iter().flatten();
+    let labels: Vec<String> = added
+        .map(|l| {
+            format!("+{}", l.name.as_deref().unwrap_or("?"))
+                .green()
+                .to_string()
+        })
+        .chain(removed.map(|l| {
+            format!("-{}", l.name.as_deref().unwrap_or("?"))
+                .red()
+                .to_string()
+        }))
+        .collect();
+    if !labels.is_empty() {
+        changes.push(("Labels:", labels.join(" ")));
+    }
+    if entry.from_priority.is_some() || entry.to_priority.is_some() {
+        let priority = |p: Option<f64>| p.map(|p| priority_label(p as i32).to_string());
+        let change = transition(priority(entry.from_priority), priority(entry.to_priority));
+        changes.push(("Priority:", change));
+    }
+    if entry.from_estimate.is_some() || entry.to_estimate.is_some() {
+        let change = transition(
+            entry.from_estimate.map(format_number),
+            entry.to_estimate.map(format_number),
+        );
+        changes.push(("Estimate:", change));
+    }
+    if entry.from_due_date.is_some() || entry.to_due_date.is_some() {
+        let change = transition(entry.from_due_date.clone(), entry.to_due_date.clone());
+        changes.push(("Due:", change));
+    }
+    if entry.from_project.is_some() || entry.to_project.is_some() {
+        let project = |p: &Option<Project>| p.as_ref().and_then(|p| p.name.clone());
+        let change = transition(project(&entry.from_project), project(&entry.to_project));
+        changes.push(("Project:", change));
+    }
+    if entry.from_cycle.is_some() || entry.to_cycle.is_some() {
+        let cycle = |c: &Option<Cycle>| {
+            c.as_ref()
+                .and_then(|c| c.number.map(format_number).or_else(|| c.name.clone()))
+        };
+        let change = transition(cycle(&entry.from_cycle), cycle(&entry.to_cycle));
+        changes.push(("Cycle:", change));
+    }
+    if entry.from_parent.is_some() || entry.to_parent.is_some() {
+        let parent = |p: &Option<Box<Issue>>| p.as_ref().and_then(|p| p.identifier.clone());
+        let change = transition(parent(&entry.from_parent), parent(&entry.to_parent));
+        changes.push(("Parent:", change));
+    }
+    if entry.updated_description == Some(true) {
+        changes.push(("Edited:", "description".to_string()));
+    }
+    changes
 }
 
 pub fn team_table(teams: &[Team]) {
@@ -361,5 +933,68 @@ pub fn team_table(teams: &[Team]) {
 
     let mut table = builder.build();
     table.with(Style::rounded());
-    println!("{table}");
+    pager::page(&table.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn width_of_cjk_text() {
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("ENG 日本"), 8);
+    }
+
+    #[test]
+    fn width_of_emoji_zwj_sequence() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(display_width(family), 2);
+        assert_eq!(
+            truncate_visible(&format!("{family}{family}ab"), 3),
+            format!("{family}…")
+        );
+    }
+
+    #[test]
+    fn width_of_combining_marks() {
+        assert_eq!(display_width("e\u{301}te\u{301}"), 3);
+        assert_eq!(truncate_visible("e\u{301}e\u{301}e\u{301}", 2), "e\u{301}…");
+    }
+
+    #[test]
+    fn truncate_cjk_on_grapheme_boundary() {
+        assert_eq!(truncate_visible("日本語テキスト", 5), "日本…");
+        assert_eq!(truncate_visible("日本語テキスト", 6), "日本…");
+        assert_eq!(truncate_visible("日本語", 6), "日本語");
+    }
+
+    #[test]
+    fn sgr_escapes_take_no_width() {
+        let red = "\x1b[31mred text\x1b[0m";
+        assert_eq!(display_width(red), 8);
+        assert_eq!(truncate_visible(red, 4), "\x1b[31mred…\x1b[0m");
+        assert_eq!(pad_visible(red, 10), format!("{red}  "));
+    }
+
+    #[test]
+    fn truncate_closes_open_hyperlink() {
+        let link = hyperlink("long title", "https://linear.app/x");
+        assert_eq!(display_width(&link), 10);
+        assert_eq!(
+            truncate_visible(&link, 5),
+            "\x1b]8;;https://linear.app/x\x1b\\long…\x1b]8;;\x1b\\\x1b[0m"
+        );
+        let bel = "\x1b]8;;https://linear.app/x\x07long title\x1b]8;;\x07";
+        assert!(truncate_visible(bel, 5).ends_with("…\x1b]8;;\x1b\\\x1b[0m"));
+    }
+
+    #[test]
+    fn truncate_after_closed_hyperlink_does_not_reclose() {
+        let text = format!("{} and more text", hyperlink("ab", "https://x"));
+        assert_eq!(
+            truncate_visible(&text, 6),
+            "\x1b]8;;https://x\x1b\\ab\x1b]8;;\x1b\\ an…\x1b[0m"
+        );
+    }
 }
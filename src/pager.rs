@@ -0,0 +1,80 @@
+use crossterm::terminal;
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const DEFAULT_PAGER: &str = "less -R";
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turn paging on or off for this process (`--no-pager`, `pager = false` in config).
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Print `text`, piping it through the pager when it would not fit on screen.
+pub fn page(text: &str) {
+    let Some(command) = pager_command().filter(|_| should_page(text)) else {
+        println!("{text}");
+        return;
+    };
+    if spawn(&command, text).is_err() {
+        println!("{text}");
+    }
+}
+
+fn should_page(text: &str) -> bool {
+    if !ENABLED.load(Ordering::Relaxed) || !std::io::stdout().is_terminal() {
+        return false;
+    }
+    let Ok((_, height)) = terminal::size() else {
+        return false;
+    };
+    text.lines().count() >= height as usize
+}
+
+/// `LINEAR_PAGER` wins over `PAGER`; an empty value or `cat` disables paging.
+fn pager_command() -> Option<String> {
+    let command = std::env::var("LINEAR_PAGER")
+        .or_else(|_| std::env::var("PAGER"))
+        .unwrap_or_else(|_| DEFAULT_PAGER.to_string());
+    let command = command.trim();
+    if command.is_empty() || command == "cat" {
+        return None;
+    }
+    Some(command.to_string())
+}
+
+/// Fails when the pager could not show `text`: it did not start, or it exited
+/// with an error without reading it all (e.g. `sh` returning 127 for a
+/// misspelled `$PAGER`), so the caller prints it instead.
+fn spawn(command: &str, text: &str) -> std::io::Result<()> {
+    let mut child = shell(command).stdin(Stdio::piped()).spawn()?;
+    let mut written = true;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything.
+        written = writeln!(stdin, "{text}").is_ok();
+    }
+    let status = child.wait()?;
+    // Short text fits in the pipe buffer even when nothing reads it, so the
+    // shell's "cannot run" codes count as unread too.
+    let not_run = matches!(status.code(), Some(126 | 127));
+    if !status.success() && (!written || not_run) {
+        return Err(std::io::Error::other(format!("pager exited with {status}")));
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(not(unix))]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
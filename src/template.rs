@@ -0,0 +1,470 @@
+use anyhow::{Context, Result, bail};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, Utc};
+use colored::{Color, Colorize};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::TemplateArgs;
+use crate::output;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_JOIN_SEPARATOR: &str = ", ";
+
+/// A parsed `--template` string, e.g. `{{identifier}}\t{{title | truncate 60}}`.
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+enum Segment {
+    Text(String),
+    Expr {
+        path: Vec<String>,
+        filters: Vec<Filter>,
+    },
+}
+
+enum Filter {
+    Truncate(usize),
+    Upper,
+    Lower,
+    Date(String),
+    Relative,
+    Default(String),
+    Join(String),
+    Color(Color),
+}
+
+/// Load the template selected by `--template` or `--template-file`, if any.
+pub fn from_args(args: &TemplateArgs) -> Result<Option<Template>> {
+    if let Some(raw) = &args.template {
+        return Template::parse(raw).map(Some);
+    }
+    if let Some(path) = &args.template_file {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read template file {}", path.display()))?;
+        let raw = raw.strip_suffix('\n').unwrap_or(&raw);
+        return Template::parse(raw)
+            .with_context(|| format!("Invalid template in {}", path.display()))
+            .map(Some);
+    }
+    Ok(None)
+}
+
+/// Print one rendered line per item.
+pub fn print_each<T: Serialize>(template: &Template, items: &[T]) -> Result<()> {
+    for item in items {
+        print_one(template, item)?;
+    }
+    Ok(())
+}
+
+pub fn print_one<T: Serialize>(template: &Template, item: &T) -> Result<()> {
+    let value = serde_json::to_value(item)?;
+    println!("{}", template.render(&value));
+    Ok(())
+}
+
+impl Template {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut rest = raw;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(unescape(&rest[..start])));
+            }
+            let after = &rest[start + 2..];
+            let end = find_expr_end(after)
+                .with_context(|| format!("Unclosed '{{{{' in template at: {{{{{after}"))?;
+            segments.push(parse_expr(&after[..end])?);
+            rest = &after[end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(unescape(rest)));
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn render(&self, value: &Value) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::Expr { path, filters } => {
+                    let mut current = lookup(value, path);
+                    for filter in filters {
+                        current = apply_filter(filter, current);
+                    }
+                    out.push_str(&value_to_text(&current));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn find_expr_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if s[i..].starts_with("}}") => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+fn parse_expr(expr: &str) -> Result<Segment> {
+    let mut parts = split_pipes(expr).into_iter();
+    let path = parts.next().unwrap_or_default();
+    let path = path.trim();
+    if path.is_empty() {
+        bail!("Empty template expression '{{{{{expr}}}}}'");
+    }
+    let path = if path == "." {
+        Vec::new()
+    } else {
+        path.split('.').map(str::to_string).collect()
+    };
+
+    let filters = parts
+        .map(|part| parse_filter(&part))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Segment::Expr { path, filters })
+}
+
+fn split_pipes(expr: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for c in expr.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '|' => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            None => {}
+        }
+        current.push(c);
+    }
+    parts.push(current);
+    parts
+}
+
+fn tokenize(s: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c == '"' || c == '\'' {
+            chars.next();
+            let mut token = String::new();
+            let mut closed = false;
+            for next in chars.by_ref() {
+                if next == c {
+                    closed = true;
+                    break;
+                }
+                token.push(next);
+            }
+            if !closed {
+                bail!("Unterminated quote in template filter '{s}'");
+            }
+            tokens.push(unescape(&token));
+        } else {
+            let mut token = String::new();
+            while let Some(&next) = chars.peek() {
+                if next.is_whitespace() {
+                    break;
+                }
+                token.push(next);
+                chars.next();
+            }
+            tokens.push(token);
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_filter(raw: &str) -> Result<Filter> {
+    let tokens = tokenize(raw)?;
+    let Some((name, args)) = tokens.split_first() else {
+        bail!("Empty filter in template");
+    };
+    let arg = args.first().map(String::as_str);
+    if args.len() > 1 {
+        bail!("Filter '{name}' takes at most one argument");
+    }
+
+    let filter = match name.as_str() {
+        "truncate" => {
+            let n = arg.context("Filter 'truncate' needs a width, e.g. `truncate 60`")?;
+            let n = n
+                .parse::<usize>()
+                .with_context(|| format!("Invalid width '{n}' for filter 'truncate'"))?;
+            Filter::Truncate(n)
+        }
+        "upper" => Filter::Upper,
+        "lower" => Filter::Lower,
+        "date" => match arg {
+            Some("relative") => Filter::Relative,
+            Some(format) => {
+                if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+                    bail!("Invalid date format '{format}'");
+                }
+                Filter::Date(format.to_string())
+            }
+            None => Filter::Date(DEFAULT_DATE_FORMAT.to_string()),
+        },
+        "default" => Filter::Default(arg.unwrap_or_default().to_string()),
+        "join" => Filter::Join(arg.unwrap_or(DEFAULT_JOIN_SEPARATOR).to_string()),
+        "color" => {
+            let name = arg.context("Filter 'color' needs a color name, e.g. `color red`")?;
+            let color = name
+                .replace(['_', '-'], " ")
+                .parse::<Color>()
+                .map_err(|_| anyhow::anyhow!("Unknown color '{name}'"))?;
+            Filter::Color(color)
+        }
+        other => bail!(
+            "Unknown template filter '{other}'. Available: truncate, upper, lower, date, default, join, color"
+        ),
+    };
+    Ok(filter)
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn lookup(value: &Value, path: &[String]) -> Value {
+    let Some((key, rest)) = path.split_first() else {
+        return value.clone();
+    };
+    match value {
+        Value::Object(map) => match map.get(key) {
+            Some(next) => lookup(next, rest),
+            // `labels.name` reads through a connection's `nodes`.
+            None if map.contains_key("nodes") => lookup(&Value::Array(list_items(value)), path),
+            None => Value::Null,
+        },
+        Value::Array(items) => match key.parse::<usize>() {
+            Ok(index) => items
+                .get(index)
+                .map(|item| lookup(item, rest))
+                .unwrap_or(Value::Null),
+            Err(_) => Value::Array(items.iter().map(|item| lookup(item, path)).collect()),
+        },
+        _ => Value::Null,
+    }
+}
+
+fn apply_filter(filter: &Filter, value: Value) -> Value {
+    match filter {
+        Filter::Truncate(n) => Value::String(output::truncate_visible(&value_to_text(&value), *n)),
+        Filter::Upper => Value::String(value_to_text(&value).to_uppercase()),
+        Filter::Lower => Value::String(value_to_text(&value).to_lowercase()),
+        Filter::Date(format) => match &value {
+            Value::String(s) => Value::String(format_date(s, format)),
+            _ => value,
+        },
+        Filter::Relative => match &value {
+            Value::String(s) => Value::String(output::relative_time(s)),
+            _ => value,
+        },
+        Filter::Default(fallback) => {
+            if is_empty(&value) {
+                Value::String(fallback.clone())
+            } else {
+                value
+            }
+        }
+        Filter::Join(separator) => {
+            let items = list_items(&value);
+            Value::String(
+                items
+                    .iter()
+                    .map(value_to_text)
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(separator),
+            )
+        }
+        Filter::Color(color) => Value::String(value_to_text(&value).color(*color).to_string()),
+    }
+}
+
+fn format_date(raw: &str, format: &str) -> String {
+    if let Ok(dt) = raw.parse::<DateTime<Utc>>() {
+        return dt.format(format).to_string();
+    }
+    if let Ok(date) = raw.parse::<NaiveDate>() {
+        return date.format(format).to_string();
+    }
+    raw.to_string()
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(_) => list_items(value).is_empty() && value_to_text(value).is_empty(),
+        _ => false,
+    }
+}
+
+/// Connections (`{ "nodes": [...] }`) behave like their node list.
+fn list_items(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items.clone(),
+        Value::Object(map) => match map.get("nodes") {
+            Some(Value::Array(items)) => items.clone(),
+            _ => vec![value.clone()],
+        },
+        Value::Null => Vec::new(),
+        other => vec![other.clone()],
+    }
+}
+
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::Array(_) => list_items(value)
+            .iter()
+            .map(value_to_text)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(DEFAULT_JOIN_SEPARATOR),
+        Value::Object(map) => {
+            if map.contains_key("nodes") {
+                return value_to_text(&Value::Array(list_items(value)));
+            }
+            ["identifier", "displayName", "name", "key", "body", "id"]
+                .iter()
+                .find_map(|key| map.get(*key).and_then(Value::as_str))
+                .map(str::to_string)
+                .unwrap_or_else(|| value.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(raw: &str, value: &Value) -> String {
+        Template::parse(raw).unwrap().render(value)
+    }
+
+    fn parse_error(raw: &str) -> String {
+        match Template::parse(raw) {
+            Ok(_) => panic!("'{raw}' should not parse"),
+            Err(err) => format!("{err:#}"),
+        }
+    }
+
+    #[test]
+    fn renders_text_and_nested_fields() {
+        let issue = json!({ "identifier": "ENG-1", "state": { "name": "Todo" } });
+        assert_eq!(
+            render("{{identifier}} is {{ state.name }}", &issue),
+            "ENG-1 is Todo"
+        );
+        assert_eq!(render("no fields", &issue), "no fields");
+    }
+
+    #[test]
+    fn reads_through_connections_and_indexes() {
+        let issue = json!({ "labels": { "nodes": [{ "name": "bug" }, { "name": "ui" }] } });
+        assert_eq!(render("{{labels.name}}", &issue), "bug, ui");
+        assert_eq!(render("{{labels.1.name}}", &issue), "ui");
+        assert_eq!(render("{{labels.name | join ' / '}}", &issue), "bug / ui");
+    }
+
+    #[test]
+    fn unknown_fields_render_empty() {
+        // Optional fields are left out of the JSON, so a missing field is not an error.
+        let issue = json!({ "identifier": "ENG-1" });
+        assert_eq!(render("[{{nope}}]", &issue), "[]");
+        assert_eq!(render("{{assignee.name | default '-'}}", &issue), "-");
+    }
+
+    #[test]
+    fn unescapes_text_and_quoted_arguments() {
+        let issue = json!({ "a": "x", "b": null });
+        assert_eq!(render(r"{{a}}\t{{a}}\n\\", &issue), "x\tx\n\\");
+        assert_eq!(render(r"{{b | default 'a\tb'}}", &issue), "a\tb");
+        assert_eq!(render(r"\q", &issue), r"\q");
+    }
+
+    #[test]
+    fn pipes_and_braces_inside_quotes_are_literal() {
+        let issue = json!({ "labels": ["a", "b"], "title": null });
+        assert_eq!(render("{{labels | join ' | '}}", &issue), "a | b");
+        assert_eq!(render("{{title | default \"}}\"}}", &issue), "}}");
+    }
+
+    #[test]
+    fn chains_filters_with_arguments() {
+        let issue = json!({ "title": "Fix the login page", "createdAt": "2024-03-05T10:00:00Z" });
+        assert_eq!(render("{{title | truncate 7 | upper}}", &issue), "FIX TH…");
+        assert_eq!(render("{{title | lower}}", &issue), "fix the login page");
+        assert_eq!(render("{{createdAt | date}}", &issue), "2024-03-05");
+        assert_eq!(render("{{createdAt | date '%d/%m'}}", &issue), "05/03");
+    }
+
+    #[test]
+    fn rejects_unknown_filters() {
+        assert!(parse_error("{{title | shout}}").contains("Unknown template filter 'shout'"));
+        assert!(parse_error("{{title | color mauve}}").contains("Unknown color 'mauve'"));
+    }
+
+    #[test]
+    fn rejects_bad_filter_arguments() {
+        assert!(parse_error("{{title | truncate}}").contains("needs a width"));
+        assert!(parse_error("{{title | truncate wide}}").contains("Invalid width 'wide'"));
+        assert!(parse_error("{{title | truncate 1 2}}").contains("at most one argument"));
+        // The open quote hides the closing braces.
+        assert!(parse_error("{{title | default 'x}}").contains("Unclosed"));
+        assert!(parse_error("{{createdAt | date '%Q'}}").contains("Invalid date format"));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert!(parse_error("{{title").contains("Unclosed"));
+        assert!(parse_error("{{ }}").contains("Empty template expression"));
+        assert!(parse_error("{{title | }}").contains("Empty filter"));
+    }
+}
@@ -0,0 +1,424 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+use crate::cli::ColorChoice;
+use crate::config::ThemeConfig;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Resolved output styles. Built once from `[theme]` in config.toml.
+struct Theme {
+    states: HashMap<String, Style>,
+    priorities: HashMap<String, Style>,
+    label_colors: bool,
+    depth: ColorDepth,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ColorDepth {
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+#[derive(Clone, Default)]
+struct Style {
+    fg: Option<Color>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Color {
+    /// SGR foreground code, 30–37 or 90–97.
+    Named(u8),
+    Rgb(u8, u8, u8),
+}
+
+const DARK_STATES: &[(&str, &str)] = &[
+    ("triage", "magenta"),
+    ("backlog", "dimmed"),
+    ("unstarted", "dimmed"),
+    ("started", "yellow"),
+    ("completed", "green"),
+    ("canceled", "red strikethrough"),
+];
+
+const LIGHT_STATES: &[(&str, &str)] = &[
+    ("triage", "magenta"),
+    ("backlog", "bright black"),
+    ("unstarted", "bright black"),
+    ("started", "blue"),
+    ("completed", "green"),
+    ("canceled", "red strikethrough"),
+];
+
+const DEFAULT_PRIORITIES: &[(&str, &str)] = &[("urgent", "red bold"), ("high", "bold")];
+
+const PRIORITIES: &[&str] = &["urgent", "high", "medium", "low", "none"];
+
+/// Apply `--color`, `NO_COLOR` and `CLICOLOR_FORCE`, and load the configured theme.
+/// An invalid theme is reported and the default one used, so commands like
+/// `linear auth` keep working.
+pub fn init(choice: ColorChoice, config: Option<&ThemeConfig>) {
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => auto_color_enabled(),
+    };
+    colored::control::set_override(enabled);
+
+    let theme = Theme::from_config(config).unwrap_or_else(|err| {
+        eprintln!(
+            "{} invalid [theme] in config.toml, using the default theme: {err:#}",
+            "warning:".yellow().bold()
+        );
+        Theme::from_config(None).unwrap_or_else(|_| Theme::fallback())
+    });
+    let _ = THEME.set(theme);
+}
+
+pub fn enabled() -> bool {
+    colored::control::SHOULD_COLORIZE.should_colorize()
+}
+
+fn auto_color_enabled() -> bool {
+    if env_flag("NO_COLOR") {
+        return false;
+    }
+    if std::env::var("CLICOLOR_FORCE").is_ok_and(|v| !v.is_empty() && v != "0") {
+        return true;
+    }
+    std::io::stdout().is_terminal()
+        && !matches!(std::env::var("TERM").ok().as_deref(), Some("dumb"))
+}
+
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|v| !v.is_empty())
+}
+
+fn theme() -> &'static Theme {
+    THEME.get_or_init(|| Theme::from_config(None).unwrap_or_else(|_| Theme::fallback()))
+}
+
+/// Workflow state name styled by its type (`started`, `completed`, ...).
+pub fn state(name: &str, state_type: &str) -> String {
+    let key = match state_type {
+        "cancelled" => "canceled",
+        other => other,
+    };
+    paint(name, theme().states.get(key))
+}
+
+/// Priority label styled by priority (`urgent`, `high`, `medium`, `low`, `none`).
+pub fn priority(label: &str) -> String {
+    paint(label, theme().priorities.get(&label.to_lowercase()))
+}
+
+/// Label name in the label's own Linear color, when enabled.
+pub fn label(name: &str, hex: Option<&str>) -> String {
+    let theme = theme();
+    let color = hex.filter(|_| theme.label_colors).and_then(parse_hex);
+    let style = color.map(|fg| Style {
+        fg: Some(fg),
+        ..Style::default()
+    });
+    paint(name, style.as_ref())
+}
+
+/// SGR parameters for an RGB foreground, reduced to the terminal's color depth.
+pub fn rgb_fg(r: u8, g: u8, b: u8) -> String {
+    let style = Style {
+        fg: Some(Color::Rgb(r, g, b)),
+        ..Style::default()
+    };
+    style.sgr_codes(theme().depth).join(";")
+}
+
+fn paint(text: &str, style: Option<&Style>) -> String {
+    let Some(style) = style else {
+        return text.to_string();
+    };
+    if !enabled() {
+        return text.to_string();
+    }
+    let codes = style.sgr_codes(theme().depth);
+    if codes.is_empty() {
+        return text.to_string();
+    }
+    format!("\x1b[{}m{text}\x1b[0m", codes.join(";"))
+}
+
+impl Theme {
+    fn from_config(config: Option<&ThemeConfig>) -> Result<Self> {
+        let preset = config.and_then(|c| c.preset.as_deref()).unwrap_or("dark");
+        let state_defaults = match preset {
+            "dark" => DARK_STATES,
+            "light" => LIGHT_STATES,
+            other => bail!("Unknown theme preset '{other}'. Use \"dark\" or \"light\"."),
+        };
+
+        let mut states = parse_table(state_defaults)?;
+        let mut priorities = parse_table(DEFAULT_PRIORITIES)?;
+        if let Some(config) = config {
+            for (key, spec) in &config.states {
+                let key = match key.to_lowercase().as_str() {
+                    "cancelled" => "canceled".to_string(),
+                    other => other.to_string(),
+                };
+                let known: Vec<&str> = state_defaults.iter().map(|(state, _)| *state).collect();
+                if !known.contains(&key.as_str()) {
+                    warn_unknown_key("states", &key, &known);
+                }
+                states.insert(key, parse_style(spec)?);
+            }
+            for (key, spec) in &config.priorities {
+                let key = key.to_lowercase();
+                if !PRIORITIES.contains(&key.as_str()) {
+                    warn_unknown_key("priorities", &key, PRIORITIES);
+                }
+                priorities.insert(key, parse_style(spec)?);
+            }
+        }
+
+        Ok(Self {
+            states,
+            priorities,
+            label_colors: config.and_then(|c| c.label_colors).unwrap_or(true),
+            depth: detect_depth(),
+        })
+    }
+
+    fn fallback() -> Self {
+        Self {
+            states: HashMap::new(),
+            priorities: HashMap::new(),
+            label_colors: false,
+            depth: ColorDepth::Ansi16,
+        }
+    }
+}
+
+/// A key that no state type or priority uses is most likely a typo.
+fn warn_unknown_key(table: &str, key: &str, known: &[&str]) {
+    eprintln!(
+        "{} unknown key '{key}' in [theme.{table}] (expected one of: {})",
+        "warning:".yellow().bold(),
+        known.join(", ")
+    );
+}
+
+fn parse_table(entries: &[(&str, &str)]) -> Result<HashMap<String, Style>> {
+    entries
+        .iter()
+        .map(|(key, spec)| Ok((key.to_string(), parse_style(spec)?)))
+        .collect()
+}
+
+fn detect_depth() -> ColorDepth {
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    if matches!(colorterm.as_str(), "truecolor" | "24bit") {
+        return ColorDepth::TrueColor;
+    }
+    if std::env::var("TERM").is_ok_and(|t| t.contains("256color")) {
+        return ColorDepth::Ansi256;
+    }
+    ColorDepth::Ansi16
+}
+
+/// Parse a style such as `"bright red bold"`, `"#5e6ad2 underline"` or `"dimmed"`.
+fn parse_style(spec: &str) -> Result<Style> {
+    let mut style = Style::default();
+    let mut words = spec.split_whitespace();
+    while let Some(word) = words.next() {
+        let word = word.to_lowercase();
+        match word.as_str() {
+            "bold" => style.bold = true,
+            "dim" | "dimmed" => style.dimmed = true,
+            "italic" => style.italic = true,
+            "underline" => style.underline = true,
+            "strikethrough" => style.strikethrough = true,
+            "none" | "default" => {}
+            "bright" => {
+                let Some(name) = words.next() else {
+                    bail!("Invalid theme style '{spec}': 'bright' needs a color");
+                };
+                let code = named_color(&name.to_lowercase())
+                    .filter(|code| *code < 90)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown color 'bright {name}' in '{spec}'"))?;
+                style.fg = Some(Color::Named(code + 60));
+            }
+            hex if hex.starts_with('#') => {
+                style.fg = Some(
+                    parse_hex(hex)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid hex color '{hex}' in '{spec}'"))?,
+                );
+            }
+            name => {
+                let code = named_color(name).ok_or_else(|| {
+                    anyhow::anyhow!("Unknown color or style '{name}' in '{spec}'")
+                })?;
+                style.fg = Some(Color::Named(code));
+            }
+        }
+    }
+    Ok(style)
+}
+
+fn named_color(name: &str) -> Option<u8> {
+    let code = match name {
+        "black" => 30,
+        "red" => 31,
+        "green" => 32,
+        "yellow" => 33,
+        "blue" => 34,
+        "magenta" | "purple" => 35,
+        "cyan" => 36,
+        "white" => 37,
+        "gray" | "grey" => 90,
+        _ => return None,
+    };
+    Some(code)
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+impl Style {
+    fn sgr_codes(&self, depth: ColorDepth) -> Vec<String> {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if self.dimmed {
+            codes.push("2".to_string());
+        }
+        if self.italic {
+            codes.push("3".to_string());
+        }
+        if self.underline {
+            codes.push("4".to_string());
+        }
+        if self.strikethrough {
+            codes.push("9".to_string());
+        }
+        match self.fg {
+            Some(Color::Named(code)) => codes.push(code.to_string()),
+            Some(Color::Rgb(r, g, b)) => codes.push(match depth {
+                ColorDepth::TrueColor => format!("38;2;{r};{g};{b}"),
+                ColorDepth::Ansi256 => format!("38;5;{}", rgb_to_ansi256(r, g, b)),
+                ColorDepth::Ansi16 => rgb_to_ansi16(r, g, b).to_string(),
+            }),
+            None => {}
+        }
+        codes
+    }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((r as u16 - 8) * 24 / 241) as u8,
+        };
+    }
+    let level = |c: u8| ((c as u16 * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    const PALETTE: [(u8, (u8, u8, u8)); 16] = [
+        (30, (0, 0, 0)),
+        (31, (205, 49, 49)),
+        (32, (13, 188, 121)),
+        (33, (229, 229, 16)),
+        (34, (36, 114, 200)),
+        (35, (188, 63, 188)),
+        (36, (17, 168, 205)),
+        (37, (229, 229, 229)),
+        (90, (102, 102, 102)),
+        (91, (241, 76, 76)),
+        (92, (35, 209, 139)),
+        (93, (245, 245, 67)),
+        (94, (59, 142, 234)),
+        (95, (214, 112, 214)),
+        (96, (41, 184, 219)),
+        (97, (255, 255, 255)),
+    ];
+    let distance = |(pr, pg, pb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, pr) + d(g, pg) + d(b, pb)
+    };
+    PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(code, _)| *code)
+        .unwrap_or(37)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(spec: &str, depth: ColorDepth) -> String {
+        parse_style(spec).unwrap().sgr_codes(depth).join(";")
+    }
+
+    #[test]
+    fn parses_named_colors_and_attributes() {
+        assert_eq!(codes("red bold", ColorDepth::Ansi16), "1;31");
+        assert_eq!(codes("Bright Blue underline", ColorDepth::Ansi16), "4;94");
+        assert_eq!(codes("grey", ColorDepth::Ansi16), "90");
+        assert_eq!(
+            codes("dim strikethrough italic", ColorDepth::Ansi16),
+            "2;3;9"
+        );
+        assert_eq!(codes("none", ColorDepth::Ansi16), "");
+    }
+
+    #[test]
+    fn hex_colors_follow_the_color_depth() {
+        assert_eq!(codes("#5e6ad2", ColorDepth::TrueColor), "38;2;94;106;210");
+        assert_eq!(codes("#5e6ad2", ColorDepth::Ansi256), "38;5;104");
+        assert_eq!(codes("#5e6ad2", ColorDepth::Ansi16), "94");
+    }
+
+    #[test]
+    fn rejects_invalid_styles() {
+        for spec in ["rainbow", "bright", "bright gray", "#12345", "#gggggg"] {
+            assert!(parse_style(spec).is_err(), "'{spec}' should not parse");
+        }
+    }
+
+    #[test]
+    fn ansi256_uses_cube_and_gray_ramp() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 243);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 255, 0), 46);
+        assert_eq!(rgb_to_ansi256(0, 0, 255), 21);
+    }
+
+    #[test]
+    fn ansi16_picks_the_nearest_color() {
+        assert_eq!(rgb_to_ansi16(0, 0, 0), 30);
+        assert_eq!(rgb_to_ansi16(255, 255, 255), 97);
+        assert_eq!(rgb_to_ansi16(200, 40, 40), 31);
+        assert_eq!(rgb_to_ansi16(20, 190, 120), 32);
+        assert_eq!(rgb_to_ansi16(100, 100, 100), 90);
+    }
+}